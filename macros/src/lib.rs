@@ -0,0 +1,150 @@
+//! The `commit!` macro, which parses a conventional commit at compile time.
+//!
+//! The macro lives in its own crate since it parses the message with
+//! `conventional-commits-types` itself.
+
+extern crate proc_macro;
+
+use conventional_commits_types::Commit;
+use proc_macro::{TokenStream, TokenTree};
+
+/// Parses a commit message literal at compile time and expands to the
+/// `Commit<'static>` it describes.
+///
+/// The message is parsed strictly, with the default options. A message that
+/// does not follow the specification is a compile error. The expansion refers
+/// to `::conventional_commits_types`, so the crate has to be a dependency
+/// under its own name.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types_macros::commit;
+///
+/// let commit = commit!("feat(api): add pagination\n\nRefs: #12");
+/// assert_eq!(commit.scope, Some("api"));
+/// assert_eq!(commit.footer[0].value, "#12");
+/// ```
+///
+/// ```compile_fail
+/// use conventional_commits_types_macros::commit;
+///
+/// let commit = commit!("Feat: add pagination");
+/// ```
+#[proc_macro]
+pub fn commit(input: TokenStream) -> TokenStream {
+    let expansion = match message(input) {
+        Ok(message) => match Commit::parse(&message) {
+            Ok(commit) => construct(&commit),
+            Err(error) => compile_error(&format!("invalid commit message: {}", error)),
+        },
+        Err(error) => compile_error(error),
+    };
+    expansion.parse().expect("the expansion is valid Rust code")
+}
+
+/// Returns the value of the single string literal of the input.
+fn message(input: TokenStream) -> Result<String, &'static str> {
+    const EXPECTED: &str = "expected a single string literal";
+    let mut tokens = input.into_iter();
+    let literal = match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Literal(literal)), None) => literal.to_string(),
+        (Some(TokenTree::Group(group)), None) => return message(group.stream()),
+        _ => return Err(EXPECTED),
+    };
+    if literal.starts_with('r') {
+        let hashes = literal[1..].len() - literal[1..].trim_start_matches('#').len();
+        let start = 1 + hashes + 1;
+        let end = literal.len() - hashes - 1;
+        if start > end {
+            return Err(EXPECTED);
+        }
+        Ok(literal[start..end].to_string())
+    } else if literal.len() >= 2 && literal.starts_with('"') && literal.ends_with('"') {
+        unescape(&literal[1..literal.len() - 1]).ok_or("invalid escape sequence")
+    } else {
+        Err(EXPECTED)
+    }
+}
+
+/// Resolves the escape sequences of the contents of a string literal.
+fn unescape(s: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => unescaped.push('\n'),
+            'r' => unescaped.push('\r'),
+            't' => unescaped.push('\t'),
+            '0' => unescaped.push('\0'),
+            '\\' => unescaped.push('\\'),
+            '\'' => unescaped.push('\''),
+            '"' => unescaped.push('"'),
+            'x' => {
+                let digits: String = chars.by_ref().take(2).collect();
+                unescaped.push(u8::from_str_radix(&digits, 16).ok()? as char);
+            }
+            'u' => {
+                if chars.next()? != '{' {
+                    return None;
+                }
+                let digits: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                let code = u32::from_str_radix(&digits.replace('_', ""), 16).ok()?;
+                unescaped.push(std::char::from_u32(code)?);
+            }
+            // A backslash at the end of a line skips the line break and the
+            // whitespace after it.
+            '\n' => {
+                while chars.peek().map_or(false, |c| c.is_whitespace()) {
+                    chars.next();
+                }
+            }
+            _ => return None,
+        }
+    }
+    Some(unescaped)
+}
+
+/// Returns the code that constructs `commit`.
+fn construct(commit: &Commit<'_>) -> String {
+    let footers: Vec<_> = commit
+        .footer
+        .iter()
+        .map(|footer| {
+            format!(
+                "::conventional_commits_types::Footer::from({:?}, \
+                 ::conventional_commits_types::FooterSeparator::from({:?}), {:?})",
+                footer.token,
+                footer.separator.as_str(),
+                footer.value
+            )
+        })
+        .collect();
+    format!(
+        "::conventional_commits_types::Commit::from({:?}, {}, {:?}, {}, {}, \
+         ::std::convert::From::from(::std::vec![{}]))",
+        commit.ty,
+        option(commit.scope),
+        commit.desc,
+        option(commit.body),
+        commit.is_breaking_change,
+        footers.join(", ")
+    )
+}
+
+/// Returns the code of an optional string.
+fn option(value: Option<&str>) -> String {
+    match value {
+        Some(value) => format!("::std::option::Option::Some({:?})", value),
+        None => "::std::option::Option::None".to_string(),
+    }
+}
+
+/// Returns the code that reports `message` as a compile error.
+fn compile_error(message: &str) -> String {
+    format!("::std::compile_error!({:?})", message)
+}
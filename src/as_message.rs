@@ -0,0 +1,44 @@
+//! Serializes a [`Commit`] as its message instead of a structure of its
+//! components.
+//!
+//! Use it with `#[serde(with = "conventional_commits_types::as_message")]`
+//! on a field of type [`Commit`]. Since a commit borrows from its message,
+//! the message is deserialized as a borrowed `&str`, so the format has to
+//! support borrowing, like `serde_json::from_str` does for strings without
+//! escape sequences.
+//!
+//! # Example
+//!
+//! ```
+//! use conventional_commits_types::Commit;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Entry<'a> {
+//!     #[serde(borrow, with = "conventional_commits_types::as_message")]
+//!     commit: Commit<'a>,
+//! }
+//!
+//! let json = r#"{"commit":"feat(api): add an endpoint"}"#;
+//! let entry: Entry<'_> = serde_json::from_str(json).unwrap();
+//! assert_eq!(entry.commit.scope, Some("api"));
+//! assert_eq!(serde_json::to_string(&entry).unwrap(), json);
+//! ```
+
+use crate::Commit;
+use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+/// Serializes a commit as its message, as written by its `Display`
+/// implementation.
+pub fn serialize<S: Serializer>(commit: &Commit<'_>, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(commit)
+}
+
+/// Deserializes a commit from its message.
+///
+/// The message is parsed with the default options, and parse errors are
+/// reported as custom errors of the format.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Commit<'de>, D::Error> {
+    let message = <&'de str>::deserialize(deserializer)?;
+    Commit::parse(message).map_err(D::Error::custom)
+}
@@ -0,0 +1,60 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The kind of a commit created for an interactive rebase with
+/// `--autosquash`, as marked by the prefix of its header.
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Autosquash {
+    /// `fixup! `, created by `git commit --fixup`.
+    Fixup,
+    /// `squash! `, created by `git commit --squash`.
+    Squash,
+    /// `amend! `, created by `git commit --fixup=amend:`.
+    Amend,
+}
+
+impl Autosquash {
+    /// Returns the prefix that marks the header, including the trailing
+    /// space.
+    pub fn prefix(self) -> &'static str {
+        match self {
+            Autosquash::Fixup => "fixup! ",
+            Autosquash::Squash => "squash! ",
+            Autosquash::Amend => "amend! ",
+        }
+    }
+
+    /// Returns the kind of the autosquash prefix of `message`, if any.
+    pub fn detect(message: &str) -> Option<Self> {
+        strip_autosquash(message).0
+    }
+}
+
+/// Removes the autosquash prefixes from the start of `message`.
+///
+/// The prefixes are removed repeatedly, as git does for the subjects of
+/// fixups of fixups. The returned kind is the one of the outermost prefix.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{strip_autosquash, Autosquash};
+///
+/// let (kind, message) = strip_autosquash("fixup! squash! feat: add a parser");
+/// assert_eq!(kind, Some(Autosquash::Fixup));
+/// assert_eq!(message, "feat: add a parser");
+/// ```
+pub fn strip_autosquash(message: &str) -> (Option<Autosquash>, &str) {
+    let mut kind = None;
+    let mut rest = message;
+    while let Some(prefix) = [Autosquash::Fixup, Autosquash::Squash, Autosquash::Amend]
+        .iter()
+        .find(|candidate| rest.starts_with(candidate.prefix()))
+    {
+        kind = kind.or(Some(*prefix));
+        rest = &rest[prefix.prefix().len()..];
+    }
+    (kind, rest)
+}
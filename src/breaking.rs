@@ -0,0 +1,99 @@
+use crate::{Commit, BREAKING_CHANGE_TOKEN};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Where a breaking change was announced.
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum BreakingSource {
+    /// The `!` marker in front of the header's colon, `feat!: ...`.
+    ///
+    /// Commits that are marked as breaking without a footer, for example by
+    /// [`Commit::set_breaking`], count as marked too.
+    Marker,
+    /// A `BREAKING CHANGE` or `BREAKING-CHANGE` footer.
+    Footer,
+    /// Both the `!` marker and a footer.
+    Both,
+}
+
+impl BreakingSource {
+    /// Returns `true` if the breaking change was marked with `!`.
+    pub fn has_marker(self) -> bool {
+        match self {
+            BreakingSource::Marker | BreakingSource::Both => true,
+            BreakingSource::Footer => false,
+        }
+    }
+
+    /// Returns `true` if the breaking change was described in a footer.
+    pub fn has_footer(self) -> bool {
+        match self {
+            BreakingSource::Footer | BreakingSource::Both => true,
+            BreakingSource::Marker => false,
+        }
+    }
+}
+
+/// The breaking change introduced by a commit.
+///
+/// Changelog generators can print the description under a "Breaking Changes"
+/// heading, no matter how the breaking change was announced.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{BreakingSource, Commit, ParseOptions};
+///
+/// let message = "feat!: drop v1\n\nBREAKING CHANGE: use v2 instead";
+/// let (commit, spans) = Commit::parse_with_spans(message, &ParseOptions::default()).unwrap();
+/// let breaking = commit.breaking_change_with_spans(&spans).unwrap();
+/// assert_eq!(breaking.source, BreakingSource::Both);
+/// assert_eq!(breaking.description, "use v2 instead");
+///
+/// let commit = Commit::parse(message).unwrap();
+/// assert_eq!(commit.breaking_change().unwrap().source, BreakingSource::Both);
+///
+/// let commit = Commit::parse("feat: drop v1\n\nBREAKING CHANGE: use v2 instead").unwrap();
+/// assert_eq!(commit.breaking_change().unwrap().source, BreakingSource::Footer);
+///
+/// let commit = Commit::parse("feat!: drop v1").unwrap();
+/// let breaking = commit.breaking_change().unwrap();
+/// assert_eq!(breaking.source, BreakingSource::Marker);
+/// assert_eq!(breaking.description, "drop v1");
+/// ```
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct BreakingChange<'a> {
+    /// Where the breaking change was announced.
+    pub source: BreakingSource,
+    /// The explanation of the breaking change.
+    ///
+    /// This is the value of the first breaking change footer, or the
+    /// description of the commit if there is none.
+    pub description: &'a str,
+}
+
+/// Returns the breaking change of `commit`, if it is breaking.
+///
+/// `marker` tells whether the header has the `!` marker.
+pub(crate) fn breaking_change<'a>(commit: &Commit<'a>, marker: bool) -> Option<BreakingChange<'a>> {
+    if !commit.is_breaking_change {
+        return None;
+    }
+    let breaking_change = match commit.footer(BREAKING_CHANGE_TOKEN) {
+        Some(footer) => BreakingChange {
+            source: if marker {
+                BreakingSource::Both
+            } else {
+                BreakingSource::Footer
+            },
+            description: footer.value,
+        },
+        None => BreakingChange {
+            source: BreakingSource::Marker,
+            description: commit.desc,
+        },
+    };
+    Some(breaking_change)
+}
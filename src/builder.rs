@@ -0,0 +1,345 @@
+use crate::{
+    format, parser, Commit, Extensions, Footer, FooterSeparator, FooterVec, Header, ParseError,
+    ParseErrorKind, ParseOptions, Span,
+};
+
+/// A builder for commits whose components are checked against the
+/// specification when the commit is built.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::CommitBuilder;
+///
+/// let commit = CommitBuilder::new()
+///     .ty("feat")
+///     .scope("api")
+///     .desc("add an endpoint")
+///     .footer("Refs", "#12")
+///     .build()
+///     .unwrap();
+/// assert_eq!(commit.to_string(), "feat(api): add an endpoint\n\nRefs: #12");
+///
+/// assert!(CommitBuilder::new().ty("feat").build().is_err());
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct CommitBuilder<'a> {
+    body: Option<&'a str>,
+    desc: &'a str,
+    footer: FooterVec<Footer<'a>>,
+    is_breaking_change: bool,
+    scope: Option<&'a str>,
+    ty: &'a str,
+}
+
+impl<'a> CommitBuilder<'a> {
+    /// Creates a builder without any components.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the type.
+    pub fn ty(mut self, ty: &'a str) -> Self {
+        self.ty = ty;
+        self
+    }
+
+    /// Sets the scope.
+    pub fn scope(mut self, scope: &'a str) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// Sets the description.
+    pub fn desc(mut self, desc: &'a str) -> Self {
+        self.desc = desc;
+        self
+    }
+
+    /// Sets the body.
+    pub fn body(mut self, body: &'a str) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Adds a footer with the `: ` separator.
+    pub fn footer(mut self, token: &'a str, value: &'a str) -> Self {
+        self.footer
+            .push(Footer::from(token, FooterSeparator::ColonSpace, value));
+        self
+    }
+
+    /// Sets whether the commit introduces a breaking change.
+    ///
+    /// Commits with a `BREAKING CHANGE` footer are breaking changes either
+    /// way.
+    pub fn breaking(mut self, is_breaking_change: bool) -> Self {
+        self.is_breaking_change = is_breaking_change;
+        self
+    }
+
+    /// Builds the commit.
+    ///
+    /// Fails if the type or the description are missing or if any component
+    /// would not be parsed back strictly, like an uppercase type, a
+    /// description with a line break or a footer token with spaces. Footer
+    /// values may span multiple lines, as long as none of the following
+    /// lines starts another footer. Errors in the header are located in the
+    /// header as it would be written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::{CommitBuilder, ParseErrorKind};
+    ///
+    /// let error = CommitBuilder::new().ty("feat").desc("a\n\nb: c").build().unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::UnexpectedNewline);
+    /// assert_eq!(error.column(), 8);
+    ///
+    /// let commit = CommitBuilder::new()
+    ///     .ty("feat")
+    ///     .desc("add a parser")
+    ///     .footer("Refs", "#12,\n  #13")
+    ///     .build();
+    /// assert!(commit.is_ok());
+    /// ```
+    pub fn build(self) -> Result<Commit<'a>, ParseError> {
+        let is_breaking_change =
+            self.is_breaking_change || self.footer.iter().any(Footer::is_breaking_change);
+        let header = Header {
+            autosquash: None,
+            desc: self.desc,
+            gitmoji: None,
+            is_breaking_change,
+            scope: self.scope,
+            ty: self.ty,
+        };
+        let mut line = String::new();
+        format::write_header(&mut line, &header, is_breaking_change)
+            .expect("writing to a String cannot fail");
+        if let Some(idx) = line.find(is_line_break) {
+            return Err(ParseError::at(
+                ParseErrorKind::UnexpectedNewline,
+                &line,
+                Span::new(idx, idx + 1),
+            ));
+        }
+        let options = ParseOptions::default();
+        let mut errors = Vec::new();
+        parser::parse_header(&line, &options, &mut errors);
+        if let Some(error) = errors.into_iter().next() {
+            return Err(error);
+        }
+
+        for footer in &self.footer {
+            // The following lines of a value are continuation lines, unless
+            // they would be parsed as footers themselves.
+            let mut lines = footer.value.split('\n').skip(1);
+            if footer.token.contains(is_line_break)
+                || lines.any(|line| parser::split_footer(line, &options).is_some())
+            {
+                return Err(ParseError::new(ParseErrorKind::UnexpectedNewline));
+            }
+            if !footer.is_valid_token() {
+                return Err(ParseError::new(ParseErrorKind::InvalidFooterToken));
+            }
+            if footer.value.trim().is_empty() {
+                return Err(ParseError::new(ParseErrorKind::MalformedFooter));
+            }
+        }
+
+        Ok(Commit {
+            autosquash: None,
+            body: self.body,
+            desc: self.desc,
+            extensions: Extensions::new(),
+            footer: self.footer,
+            gitmoji: None,
+            has_breaking_marker: self.is_breaking_change,
+            is_breaking_change,
+            scope: self.scope,
+            ty: self.ty,
+        })
+    }
+}
+
+/// Returns `true` if `c` ends a line.
+fn is_line_break(c: char) -> bool {
+    c == '\r' || c == '\n'
+}
+
+/// The state of a [`TypedCommitBuilder`] without a type.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct NoType;
+
+/// The state of a [`TypedCommitBuilder`] without a description.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct NoDesc;
+
+/// A builder for commits that can only be built once the type and the
+/// description are set.
+///
+/// The type parameters track whether they are: they are [`NoType`] and
+/// [`NoDesc`] at first and become `&str` once set, which makes `build`
+/// available. Unlike [`CommitBuilder`], the components are not checked
+/// against the specification.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::TypedCommitBuilder;
+///
+/// let commit = TypedCommitBuilder::new()
+///     .ty("fix")
+///     .desc("handle empty input")
+///     .breaking(true)
+///     .build();
+/// assert_eq!(commit.to_string(), "fix!: handle empty input");
+/// ```
+///
+/// Leaving out the description does not compile:
+///
+/// ```compile_fail
+/// use conventional_commits_types::TypedCommitBuilder;
+///
+/// let commit = TypedCommitBuilder::new().ty("fix").build();
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct TypedCommitBuilder<'a, Ty = NoType, Desc = NoDesc> {
+    body: Option<&'a str>,
+    desc: Desc,
+    footer: FooterVec<Footer<'a>>,
+    is_breaking_change: bool,
+    scope: Option<&'a str>,
+    ty: Ty,
+}
+
+impl<'a> TypedCommitBuilder<'a> {
+    /// Creates a builder without any components.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<'a, Desc> TypedCommitBuilder<'a, NoType, Desc> {
+    /// Sets the type.
+    pub fn ty(self, ty: &'a str) -> TypedCommitBuilder<'a, &'a str, Desc> {
+        TypedCommitBuilder {
+            body: self.body,
+            desc: self.desc,
+            footer: self.footer,
+            is_breaking_change: self.is_breaking_change,
+            scope: self.scope,
+            ty,
+        }
+    }
+}
+
+impl<'a, Ty> TypedCommitBuilder<'a, Ty, NoDesc> {
+    /// Sets the description.
+    pub fn desc(self, desc: &'a str) -> TypedCommitBuilder<'a, Ty, &'a str> {
+        TypedCommitBuilder {
+            body: self.body,
+            desc,
+            footer: self.footer,
+            is_breaking_change: self.is_breaking_change,
+            scope: self.scope,
+            ty: self.ty,
+        }
+    }
+}
+
+impl<'a, Ty, Desc> TypedCommitBuilder<'a, Ty, Desc> {
+    /// Sets the scope.
+    pub fn scope(mut self, scope: &'a str) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// Sets the body.
+    pub fn body(mut self, body: &'a str) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Adds a footer with the `: ` separator.
+    pub fn footer(mut self, token: &'a str, value: &'a str) -> Self {
+        self.footer
+            .push(Footer::from(token, FooterSeparator::ColonSpace, value));
+        self
+    }
+
+    /// Sets whether the commit introduces a breaking change.
+    ///
+    /// Commits with a `BREAKING CHANGE` footer are breaking changes either
+    /// way.
+    pub fn breaking(mut self, is_breaking_change: bool) -> Self {
+        self.is_breaking_change = is_breaking_change;
+        self
+    }
+}
+
+impl<'a> TypedCommitBuilder<'a, &'a str, &'a str> {
+    /// Builds the commit.
+    pub fn build(self) -> Commit<'a> {
+        let is_breaking_change =
+            self.is_breaking_change || self.footer.iter().any(Footer::is_breaking_change);
+        Commit {
+            autosquash: None,
+            body: self.body,
+            desc: self.desc,
+            extensions: Extensions::new(),
+            footer: self.footer,
+            gitmoji: None,
+            has_breaking_marker: self.is_breaking_change,
+            is_breaking_change,
+            scope: self.scope,
+            ty: self.ty,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kind(builder: CommitBuilder<'_>) -> Option<ParseErrorKind> {
+        builder.build().err().map(|error| error.kind())
+    }
+
+    #[test]
+    fn line_breaks_in_the_header_are_rejected() {
+        let builder = CommitBuilder::new().ty("feat").desc("add a parser");
+        assert_eq!(kind(builder.clone()), None);
+        assert_eq!(
+            kind(builder.clone().ty("fe\nat")),
+            Some(ParseErrorKind::UnexpectedNewline)
+        );
+        assert_eq!(
+            kind(builder.clone().scope("api\r")),
+            Some(ParseErrorKind::UnexpectedNewline)
+        );
+        assert_eq!(
+            kind(builder.desc("a\n\nb: c")),
+            Some(ParseErrorKind::UnexpectedNewline)
+        );
+    }
+
+    #[test]
+    fn line_breaks_in_footers_are_rejected() {
+        let builder = CommitBuilder::new().ty("feat").desc("add a parser");
+        assert_eq!(
+            kind(builder.clone().footer("Refs\n", "#12")),
+            Some(ParseErrorKind::UnexpectedNewline)
+        );
+        assert_eq!(
+            kind(builder.clone().footer("Refs", "#12\nCloses: #13")),
+            Some(ParseErrorKind::UnexpectedNewline)
+        );
+        assert_eq!(
+            kind(builder.clone().footer("Refs", "#12\nCloses #13")),
+            Some(ParseErrorKind::UnexpectedNewline)
+        );
+        assert_eq!(kind(builder.footer("Refs", "#12 and\nmore of #13")), None);
+    }
+}
@@ -0,0 +1,170 @@
+//! The representation of commits used by the JavaScript
+//! `conventional-commits-parser`, which `conventional-changelog` consumes.
+
+use crate::{Commit, ParseOptions, Revert, BREAKING_CHANGE_TOKEN};
+use serde::{Deserialize, Serialize};
+
+/// A commit in the shape of the output of the JavaScript
+/// `conventional-commits-parser`.
+///
+/// Serializing it yields the same fields, in camel case, so that tools can
+/// hand commits parsed in Rust to existing `conventional-changelog`
+/// pipelines.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{ChangelogCommit, Commit};
+///
+/// let commit = Commit::parse("feat(api)!: drop v1\n\nCloses #12").unwrap();
+/// let json = serde_json::to_value(ChangelogCommit::from(&commit)).unwrap();
+/// assert_eq!(json["type"], "feat");
+/// assert_eq!(json["subject"], "drop v1");
+/// assert_eq!(json["notes"][0]["title"], "BREAKING CHANGE");
+/// assert_eq!(json["references"][0]["issue"], "12");
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangelogCommit<'a> {
+    /// The type of the commit.
+    #[serde(borrow, rename = "type")]
+    pub ty: Option<&'a str>,
+    /// The scope of the commit.
+    #[serde(borrow)]
+    pub scope: Option<&'a str>,
+    /// The description of the commit.
+    #[serde(borrow)]
+    pub subject: Option<&'a str>,
+    /// The first line of a merge commit. Always `None` for conventional
+    /// commits.
+    #[serde(borrow)]
+    pub merge: Option<&'a str>,
+    /// The first line of the message.
+    pub header: String,
+    /// The body of the commit.
+    #[serde(borrow)]
+    pub body: Option<&'a str>,
+    /// The footers of the commit, one per line.
+    pub footer: Option<String>,
+    /// The breaking changes of the commit.
+    #[serde(borrow)]
+    pub notes: Vec<ChangelogNote<'a>>,
+    /// The references to issues and pull requests.
+    #[serde(borrow)]
+    pub references: Vec<ChangelogReference<'a>>,
+    /// The users mentioned in the commit, without the `@`.
+    #[serde(borrow)]
+    pub mentions: Vec<&'a str>,
+    /// The reverted commit, if the commit is a revert.
+    #[serde(borrow)]
+    pub revert: Option<ChangelogRevert<'a>>,
+}
+
+/// A note of a [`ChangelogCommit`], like a breaking change.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ChangelogNote<'a> {
+    /// The kind of the note, like `BREAKING CHANGE`.
+    pub title: &'a str,
+    /// The text of the note.
+    pub text: &'a str,
+}
+
+/// A reference of a [`ChangelogCommit`] to an issue or pull request.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ChangelogReference<'a> {
+    /// The keyword in front of the reference, like `Closes`.
+    #[serde(borrow)]
+    pub action: Option<&'a str>,
+    /// The owner of the repository, if the reference is not to the same one.
+    #[serde(borrow)]
+    pub owner: Option<&'a str>,
+    /// The repository, if the reference is not to the same one.
+    #[serde(borrow)]
+    pub repository: Option<&'a str>,
+    /// The number of the issue or pull request.
+    pub issue: String,
+    /// The reference as written, like `owner/repo#12`.
+    pub raw: String,
+    /// The prefix in front of the number, `#`.
+    pub prefix: &'a str,
+}
+
+/// The commit reverted by a [`ChangelogCommit`].
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ChangelogRevert<'a> {
+    /// The first line of the reverted commit.
+    #[serde(borrow)]
+    pub header: Option<&'a str>,
+    /// The hash of the reverted commit.
+    #[serde(borrow)]
+    pub hash: Option<&'a str>,
+}
+
+impl<'a> From<&Commit<'a>> for ChangelogCommit<'a> {
+    /// Converts a commit into the shape of the JavaScript parser.
+    ///
+    /// A breaking change that is only marked with `!` gets a note with the
+    /// description as its text, as the JavaScript parser does.
+    fn from(commit: &Commit<'a>) -> Self {
+        let mut notes: Vec<_> = commit
+            .footer
+            .iter()
+            .filter(|footer| footer.is_breaking_change())
+            .map(|footer| ChangelogNote {
+                title: BREAKING_CHANGE_TOKEN,
+                text: footer.value,
+            })
+            .collect();
+        if commit.is_breaking_change && notes.is_empty() {
+            notes.push(ChangelogNote {
+                title: BREAKING_CHANGE_TOKEN,
+                text: commit.desc,
+            });
+        }
+
+        let references = commit
+            .references()
+            .into_iter()
+            .map(|reference| {
+                let raw = match (reference.owner, reference.repo) {
+                    (Some(owner), Some(repo)) => format!("{}/{}#{}", owner, repo, reference.number),
+                    _ => format!("#{}", reference.number),
+                };
+                ChangelogReference {
+                    action: reference.action,
+                    owner: reference.owner,
+                    repository: reference.repo,
+                    issue: reference.number.to_string(),
+                    raw,
+                    prefix: "#",
+                }
+            })
+            .collect();
+
+        let footer = if commit.footer.is_empty() {
+            None
+        } else {
+            let lines: Vec<_> = commit.footer.iter().map(ToString::to_string).collect();
+            Some(lines.join("\n"))
+        };
+        let revert =
+            Revert::from_commit(commit, &ParseOptions::default()).map(|revert| ChangelogRevert {
+                header: Some(revert.subject),
+                hash: revert.commits.first().copied(),
+            });
+
+        Self {
+            ty: Some(commit.ty),
+            scope: commit.scope,
+            subject: Some(commit.desc),
+            merge: None,
+            header: commit.summary(),
+            body: commit.body.filter(|body| !body.is_empty()),
+            footer,
+            notes,
+            references,
+            mentions: commit.mentions(),
+            revert,
+        }
+    }
+}
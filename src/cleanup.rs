@@ -0,0 +1,140 @@
+use crate::parser::strip_cr;
+use std::borrow::Cow;
+
+/// The character that starts a comment line in a commit message file, unless
+/// git's `core.commentChar` is set to something else.
+pub const COMMENT_CHAR: char = '#';
+
+/// The scissors marker that git writes in front of the diff when committing
+/// with `--verbose`. It is preceded by the comment character and a space.
+pub const SCISSORS: &str = "------------------------ >8 ------------------------";
+
+/// Prepares the contents of a `COMMIT_EDITMSG` file for parsing.
+///
+/// Comment lines starting with `#` are removed, as is everything from the
+/// scissors line onwards. Leading and trailing blank lines are trimmed as
+/// well. The message is only copied if lines had to be removed from the
+/// middle of it.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{strip_comments, Commit};
+///
+/// let file = "feat: add a feature\n# Please enter the commit message.\n";
+/// let message = strip_comments(file);
+/// assert_eq!(message, "feat: add a feature");
+/// assert!(Commit::parse(&message).is_ok());
+/// ```
+pub fn strip_comments(message: &str) -> Cow<'_, str> {
+    strip_comments_with(message, COMMENT_CHAR)
+}
+
+/// Prepares the contents of a `COMMIT_EDITMSG` file for parsing, treating
+/// lines that start with `comment_char` as comments.
+///
+/// This is needed for repositories that configure `core.commentChar`. See
+/// [`strip_comments`] for details.
+pub fn strip_comments_with(message: &str, comment_char: char) -> Cow<'_, str> {
+    let mut scissors = String::with_capacity(SCISSORS.len() + 2);
+    scissors.push(comment_char);
+    scissors.push(' ');
+    scissors.push_str(SCISSORS);
+
+    // The kept lines together with their offsets in the message.
+    let mut lines = Vec::new();
+    let mut offset = 0;
+    for line in message.split('\n') {
+        if line.trim_end() == scissors {
+            break;
+        }
+        if !line.starts_with(comment_char) {
+            lines.push((offset, line));
+        }
+        offset += line.len() + 1;
+    }
+
+    // Leading and trailing blank lines are not part of the message.
+    let start = lines.iter().position(|(_, line)| !line.trim().is_empty());
+    let end = lines.iter().rposition(|(_, line)| !line.trim().is_empty());
+    let lines = match (start, end) {
+        (Some(start), Some(end)) => &lines[start..=end],
+        _ => return Cow::Borrowed(""),
+    };
+
+    let contiguous = lines
+        .windows(2)
+        .all(|pair| pair[0].0 + pair[0].1.len() + 1 == pair[1].0);
+    if contiguous {
+        let (start, _) = lines[0];
+        let (last_offset, last) = lines[lines.len() - 1];
+        Cow::Borrowed(strip_cr(&message[start..last_offset + last.len()]))
+    } else {
+        let lines: Vec<_> = lines.iter().map(|(_, line)| *line).collect();
+        let mut joined = lines.join("\n");
+        if joined.ends_with('\r') {
+            joined.pop();
+        }
+        Cow::Owned(joined)
+    }
+}
+
+/// The characters that end a line.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum LineEnding {
+    /// A single line feed, `\n`, as used by git and most platforms.
+    Lf,
+    /// A carriage return followed by a line feed, `\r\n`, as used on Windows.
+    CrLf,
+}
+
+impl LineEnding {
+    /// Returns the characters of the line ending.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
+}
+
+impl Default for LineEnding {
+    /// Returns the default LineEnding, the Lf.
+    fn default() -> Self {
+        LineEnding::Lf
+    }
+}
+
+/// Converts all line endings of `text` to the given one.
+///
+/// Any mix of `\n`, `\r\n` and lone `\r` line endings is accepted. The
+/// text is only copied if it contains a line ending that has to be changed.
+pub fn normalize_line_endings(text: &str, ending: LineEnding) -> Cow<'_, str> {
+    let needs_change = match ending {
+        LineEnding::Lf => text.contains('\r'),
+        LineEnding::CrLf => text.char_indices().any(|(idx, c)| match c {
+            '\r' => !text[idx + 1..].starts_with('\n'),
+            '\n' => !text[..idx].ends_with('\r'),
+            _ => false,
+        }),
+    };
+    if !needs_change {
+        return Cow::Borrowed(text);
+    }
+
+    let mut normalized = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\r' => {
+                if chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+                normalized.push_str(ending.as_str());
+            }
+            '\n' => normalized.push_str(ending.as_str()),
+            c => normalized.push(c),
+        }
+    }
+    Cow::Owned(normalized)
+}
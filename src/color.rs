@@ -0,0 +1,81 @@
+//! Rendering of commits with ANSI colors for terminals.
+
+use crate::{format, Commit, SEPARATOR_COLON};
+use std::fmt;
+
+/// The style of the type, bold blue.
+const TYPE_STYLE: &str = "\x1b[1;34m";
+/// The style of the scope, yellow.
+const SCOPE_STYLE: &str = "\x1b[33m";
+/// The style of the `!` marker and breaking change footer tokens, bold red.
+const BREAKING_STYLE: &str = "\x1b[1;31m";
+/// The style of the other footer tokens, green.
+const TOKEN_STYLE: &str = "\x1b[32m";
+/// Resets all styles.
+const RESET: &str = "\x1b[0m";
+
+/// A commit that is displayed with ANSI colors.
+///
+/// The message is the same as the one of the commit's `Display`
+/// implementation, but the type, the scope, the breaking change marker and
+/// the footer tokens are highlighted. Created by [`Commit::colored`].
+#[derive(Copy, Clone, Debug)]
+pub struct Colored<'c, 'a> {
+    commit: &'c Commit<'a>,
+}
+
+impl<'c, 'a> Colored<'c, 'a> {
+    pub(crate) fn new(commit: &'c Commit<'a>) -> Self {
+        Self { commit }
+    }
+}
+
+impl fmt::Display for Colored<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let commit = self.commit;
+        if let Some(autosquash) = commit.autosquash {
+            f.write_str(autosquash.prefix())?;
+        }
+        if let Some(gitmoji) = commit.gitmoji {
+            write!(f, "{} ", gitmoji)?;
+        }
+        paint(f, TYPE_STYLE, commit.ty)?;
+        if let Some(scope) = commit.scope {
+            f.write_str("(")?;
+            paint(f, SCOPE_STYLE, scope)?;
+            f.write_str(")")?;
+        }
+        let has_breaking_footer = commit
+            .footer
+            .iter()
+            .any(|footer| footer.is_breaking_change());
+        if commit.is_breaking_change && !has_breaking_footer {
+            paint(f, BREAKING_STYLE, "!")?;
+        }
+        write!(f, "{}{}", SEPARATOR_COLON, commit.desc)?;
+
+        if let Some(body) = commit.body.filter(|body| !body.is_empty()) {
+            write!(f, "\n\n{}", body)?;
+        }
+        if !commit.footer.is_empty() {
+            f.write_str("\n")?;
+        }
+        for footer in &commit.footer {
+            f.write_str("\n")?;
+            let style = if footer.is_breaking_change() {
+                BREAKING_STYLE
+            } else {
+                TOKEN_STYLE
+            };
+            paint(f, style, footer.token)?;
+            write!(f, "{}", footer.separator)?;
+            format::write_lines(f, footer.value, None, "\n")?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes `text` in the given style.
+fn paint(f: &mut fmt::Formatter<'_>, style: &str, text: &str) -> fmt::Result {
+    write!(f, "{}{}{}", style, text, RESET)
+}
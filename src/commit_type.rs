@@ -0,0 +1,197 @@
+use crate::{ParseError, ParseErrorKind};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+
+/// The type of a commit, with variants for the well-known types.
+///
+/// Matching on it avoids comparing strings, and typos in type names become
+/// compile errors. As the specification requires, the well-known types are
+/// recognized regardless of their case. It is serialized as a string.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{Commit, CommitType};
+///
+/// let commit = Commit::parse("feat(api): add an endpoint").unwrap();
+/// match commit.commit_type() {
+///     CommitType::Feat => {}
+///     other => panic!("unexpected type {}", other),
+/// }
+/// assert_eq!(CommitType::from("deps"), CommitType::Custom("deps"));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CommitType<'a> {
+    /// `feat`, a new feature.
+    Feat,
+    /// `fix`, a bug fix.
+    Fix,
+    /// `docs`, a change of the documentation only.
+    Docs,
+    /// `chore`, a change that does not affect the source or the tests.
+    Chore,
+    /// `refactor`, a change that neither fixes a bug nor adds a feature.
+    Refactor,
+    /// `perf`, a change that improves performance.
+    Perf,
+    /// `test`, a change of the tests only.
+    Test,
+    /// `build`, a change of the build system or the dependencies.
+    Build,
+    /// `ci`, a change of the CI configuration.
+    Ci,
+    /// `style`, a change that does not affect the meaning of the code.
+    Style,
+    /// `revert`, a commit that reverts an earlier one.
+    Revert,
+    /// Any other type, as written in the message.
+    Custom(&'a str),
+}
+
+impl<'a> CommitType<'a> {
+    /// Returns the type as written in a message.
+    ///
+    /// Well-known types are returned in lowercase.
+    pub fn as_str(&self) -> &'a str {
+        match self {
+            CommitType::Feat => "feat",
+            CommitType::Fix => "fix",
+            CommitType::Docs => "docs",
+            CommitType::Chore => "chore",
+            CommitType::Refactor => "refactor",
+            CommitType::Perf => "perf",
+            CommitType::Test => "test",
+            CommitType::Build => "build",
+            CommitType::Ci => "ci",
+            CommitType::Style => "style",
+            CommitType::Revert => "revert",
+            CommitType::Custom(ty) => ty,
+        }
+    }
+
+    /// Returns what commits of the type are about, as commitizen shows it
+    /// when picking a type, like `A new feature`.
+    ///
+    /// Returns `None` for custom types.
+    pub fn description(&self) -> Option<&'static str> {
+        let description = match self {
+            CommitType::Feat => "A new feature",
+            CommitType::Fix => "A bug fix",
+            CommitType::Docs => "Documentation only changes",
+            CommitType::Chore => "Other changes that don't modify src or test files",
+            CommitType::Refactor => "A code change that neither fixes a bug nor adds a feature",
+            CommitType::Perf => "A code change that improves performance",
+            CommitType::Test => "Adding missing tests or correcting existing tests",
+            CommitType::Build => "Changes that affect the build system or external dependencies",
+            CommitType::Ci => "Changes to the CI configuration files and scripts",
+            CommitType::Style => "Changes that do not affect the meaning of the code",
+            CommitType::Revert => "Reverts a previous commit",
+            CommitType::Custom(_) => return None,
+        };
+        Some(description)
+    }
+
+    /// Returns the heading of the section of a changelog that lists the
+    /// commits of the type, like `Features`.
+    ///
+    /// The headings are the ones of the `conventionalcommits` preset of
+    /// `conventional-changelog`. Returns `None` for custom types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::CommitType;
+    ///
+    /// assert_eq!(CommitType::Fix.changelog_title(), Some("Bug Fixes"));
+    /// assert!(!CommitType::Fix.is_hidden());
+    /// assert!(CommitType::Chore.is_hidden());
+    /// ```
+    pub fn changelog_title(&self) -> Option<&'static str> {
+        let title = match self {
+            CommitType::Feat => "Features",
+            CommitType::Fix => "Bug Fixes",
+            CommitType::Docs => "Documentation",
+            CommitType::Chore => "Miscellaneous Chores",
+            CommitType::Refactor => "Code Refactoring",
+            CommitType::Perf => "Performance Improvements",
+            CommitType::Test => "Tests",
+            CommitType::Build => "Build System",
+            CommitType::Ci => "Continuous Integration",
+            CommitType::Style => "Styles",
+            CommitType::Revert => "Reverts",
+            CommitType::Custom(_) => return None,
+        };
+        Some(title)
+    }
+
+    /// Returns `true` if changelogs leave out commits of the type by
+    /// default.
+    ///
+    /// As in the `conventionalcommits` preset of `conventional-changelog`,
+    /// only features, bug fixes, performance improvements and reverts are
+    /// shown. Custom types are hidden.
+    pub fn is_hidden(&self) -> bool {
+        match self {
+            CommitType::Feat | CommitType::Fix | CommitType::Perf | CommitType::Revert => false,
+            _ => true,
+        }
+    }
+
+    /// Returns the well-known type that `s` is, ignoring its case.
+    fn known(s: &str) -> Option<Self> {
+        [
+            CommitType::Feat,
+            CommitType::Fix,
+            CommitType::Docs,
+            CommitType::Chore,
+            CommitType::Refactor,
+            CommitType::Perf,
+            CommitType::Test,
+            CommitType::Build,
+            CommitType::Ci,
+            CommitType::Style,
+            CommitType::Revert,
+        ]
+        .iter()
+        .copied()
+        .find(|ty| ty.as_str().eq_ignore_ascii_case(s))
+    }
+}
+
+impl fmt::Display for CommitType<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for CommitType<'a> {
+    /// Returns the well-known type that `s` is, or a custom one.
+    fn from(s: &'a str) -> Self {
+        Self::known(s).unwrap_or(CommitType::Custom(s))
+    }
+}
+
+impl FromStr for CommitType<'_> {
+    type Err = ParseError;
+
+    /// Parses one of the well-known types. Custom types borrow from their
+    /// input and are created with `From<&str>` instead.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::known(s).ok_or_else(|| ParseError::new(ParseErrorKind::InvalidType))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for CommitType<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de: 'a, 'a> Deserialize<'de> for CommitType<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <&'de str>::deserialize(deserializer).map(Self::from)
+    }
+}
@@ -0,0 +1,54 @@
+use crate::BreakingStyle;
+
+/// The types allowed by the Angular convention, in addition to `revert`.
+pub const ANGULAR_TYPES: &[&str] = &[
+    "build", "ci", "docs", "feat", "fix", "perf", "refactor", "revert", "test",
+];
+
+/// A commit message convention.
+///
+/// The Conventional Commits specification grew out of the commit message
+/// guidelines of the Angular project, which differ in a few details. Parsing
+/// with [`Convention::Angular`] gives the same results as the tools of the
+/// `conventional-changelog` project do with their Angular preset:
+///
+/// - only the types `build`, `ci`, `docs`, `feat`, `fix`, `perf`, `refactor`,
+///   `revert` and `test` are allowed,
+/// - the `!` breaking change marker is not allowed,
+/// - only `BREAKING CHANGE` footers mark breaking changes, not
+///   `BREAKING-CHANGE` ones.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Convention {
+    /// The Conventional Commits specification.
+    Conventional,
+    /// The Angular commit message guidelines.
+    Angular,
+}
+
+impl Convention {
+    /// Returns how breaking changes are expressed by the convention.
+    ///
+    /// Use this with [`Commit::to_message`](crate::Commit::to_message) to
+    /// format commits that follow the convention.
+    pub fn breaking_style(self) -> BreakingStyle {
+        match self {
+            Convention::Conventional => BreakingStyle::Bang,
+            Convention::Angular => BreakingStyle::Footer,
+        }
+    }
+
+    /// Returns `true` if commits of the type `ty` are allowed.
+    pub fn allows_type(self, ty: &str) -> bool {
+        match self {
+            Convention::Conventional => true,
+            Convention::Angular => ANGULAR_TYPES.contains(&ty),
+        }
+    }
+}
+
+impl Default for Convention {
+    /// Returns the default Convention, the Conventional.
+    fn default() -> Self {
+        Convention::Conventional
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{Autosquash, Commit, CommitOwned, Extensions, Footer, FooterOwned, FooterSeparator};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{
+    borrow::Cow,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+/// A commit whose components are either borrowed from the message or owned.
+///
+/// Components can be replaced individually, for example to rewrite the scope,
+/// while all others keep borrowing from the message. When deserialized with
+/// serde, components are borrowed from the input where possible and copied
+/// where they contain escape sequences.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{Commit, CommitCow};
+///
+/// let commit = Commit::parse("feat(Parser): add a lexer").unwrap();
+/// let mut cow = CommitCow::from(commit);
+/// if let Some(scope) = &mut cow.scope {
+///     *scope.to_mut() = scope.to_lowercase();
+/// }
+/// assert_eq!(cow.to_string(), "feat(parser): add a lexer");
+/// ```
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CommitCow<'a> {
+    /// The kind of the autosquash prefix of the header, like `fixup! `.
+    pub autosquash: Option<Autosquash>,
+    /// The optional body.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub body: Option<Cow<'a, str>>,
+    /// The mandatory description.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub desc: Cow<'a, str>,
+    /// Extra data attached by tools, which is not part of the message.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Extensions::is_empty")
+    )]
+    pub extensions: Extensions,
+    /// A list of footers. Empty when none are part of the commit message.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub footer: Vec<FooterCow<'a>>,
+    /// The gitmoji in front of the type, like `✨` or `:sparkles:`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub gitmoji: Option<Cow<'a, str>>,
+    /// Whether the header has the `!` breaking change marker.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "crate::is_false")
+    )]
+    pub has_breaking_marker: bool,
+    /// Whether the commit introduces a breaking change.
+    pub is_breaking_change: bool,
+    /// The optional scope.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub scope: Option<Cow<'a, str>>,
+    /// The mandatory type.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub ty: Cow<'a, str>,
+}
+
+impl<'a> CommitCow<'a> {
+    /// Returns a commit that borrows the components of this one.
+    pub fn as_commit(&self) -> Commit<'_> {
+        Commit {
+            autosquash: self.autosquash,
+            body: self.body.as_deref(),
+            desc: &self.desc,
+            extensions: self.extensions.clone(),
+            footer: self.footer.iter().map(FooterCow::as_footer).collect(),
+            gitmoji: self.gitmoji.as_deref(),
+            has_breaking_marker: self.has_breaking_marker,
+            is_breaking_change: self.is_breaking_change,
+            scope: self.scope.as_deref(),
+            ty: &self.ty,
+        }
+    }
+
+    /// Converts the commit into one that owns all of its components.
+    pub fn into_owned(self) -> CommitOwned {
+        CommitOwned {
+            autosquash: self.autosquash,
+            body: self.body.map(Cow::into_owned),
+            desc: self.desc.into_owned(),
+            extensions: self.extensions,
+            footer: self.footer.into_iter().map(FooterCow::into_owned).collect(),
+            gitmoji: self.gitmoji.map(Cow::into_owned),
+            has_breaking_marker: self.has_breaking_marker,
+            is_breaking_change: self.is_breaking_change,
+            scope: self.scope.map(Cow::into_owned),
+            ty: self.ty.into_owned(),
+        }
+    }
+}
+
+impl<'a> From<Commit<'a>> for CommitCow<'a> {
+    /// Converts a commit into one that borrows all of its components.
+    fn from(commit: Commit<'a>) -> Self {
+        Self {
+            autosquash: commit.autosquash,
+            body: commit.body.map(Cow::Borrowed),
+            desc: Cow::Borrowed(commit.desc),
+            extensions: commit.extensions,
+            footer: commit.footer.into_iter().map(FooterCow::from).collect(),
+            gitmoji: commit.gitmoji.map(Cow::Borrowed),
+            has_breaking_marker: commit.has_breaking_marker,
+            is_breaking_change: commit.is_breaking_change,
+            scope: commit.scope.map(Cow::Borrowed),
+            ty: Cow::Borrowed(commit.ty),
+        }
+    }
+}
+
+impl From<CommitOwned> for CommitCow<'_> {
+    /// Converts a commit into one that owns all of its components.
+    fn from(commit: CommitOwned) -> Self {
+        Self {
+            autosquash: commit.autosquash,
+            body: commit.body.map(Cow::Owned),
+            desc: Cow::Owned(commit.desc),
+            extensions: commit.extensions,
+            footer: commit.footer.into_iter().map(FooterCow::from).collect(),
+            gitmoji: commit.gitmoji.map(Cow::Owned),
+            has_breaking_marker: commit.has_breaking_marker,
+            is_breaking_change: commit.is_breaking_change,
+            scope: commit.scope.map(Cow::Owned),
+            ty: Cow::Owned(commit.ty),
+        }
+    }
+}
+
+impl fmt::Display for CommitCow<'_> {
+    /// Formats the commit as its borrowed counterpart does.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_commit().fmt(f)
+    }
+}
+
+/// A footer whose components are either borrowed or owned.
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FooterCow<'a> {
+    /// The token of the footer.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub token: Cow<'a, str>,
+    /// The separator between the token and the value, like `": "`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub separator: Cow<'a, str>,
+    /// The value of the footer.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub value: Cow<'a, str>,
+}
+
+impl<'a> FooterCow<'a> {
+    /// Returns a footer that borrows the components of this one.
+    pub fn as_footer(&self) -> Footer<'_> {
+        Footer::from(
+            &self.token,
+            FooterSeparator::from(&*self.separator),
+            &self.value,
+        )
+    }
+
+    /// Converts the footer into one that owns all of its components.
+    pub fn into_owned(self) -> FooterOwned {
+        FooterOwned {
+            token: self.token.into_owned(),
+            separator: self.separator.into_owned(),
+            value: self.value.into_owned(),
+        }
+    }
+}
+
+impl<'a> From<Footer<'a>> for FooterCow<'a> {
+    fn from(footer: Footer<'a>) -> Self {
+        Self {
+            token: Cow::Borrowed(footer.token),
+            separator: Cow::Borrowed(footer.separator.as_str()),
+            value: Cow::Borrowed(footer.value),
+        }
+    }
+}
+
+impl From<FooterOwned> for FooterCow<'_> {
+    fn from(footer: FooterOwned) -> Self {
+        Self {
+            token: Cow::Owned(footer.token),
+            separator: Cow::Owned(footer.separator),
+            value: Cow::Owned(footer.value),
+        }
+    }
+}
+
+impl Hash for CommitCow<'_> {
+    /// Hashes the commit as its borrowed counterpart does, so that the
+    /// representations can be used interchangeably as keys.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_commit().hash(state);
+    }
+}
@@ -0,0 +1,55 @@
+use std::{borrow::Cow, str};
+
+/// The encoding that is assumed for messages that are not valid UTF-8.
+///
+/// Git stores messages as raw bytes and only records an encoding if
+/// `i18n.commitEncoding` was set, so older histories often contain Latin-1
+/// messages.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Encoding {
+    /// ISO-8859-1, which maps every byte to the code point of the same value.
+    /// Decoding never loses any information.
+    Latin1,
+    /// UTF-8, replacing invalid sequences with `U+FFFD`.
+    Utf8Lossy,
+}
+
+impl Default for Encoding {
+    /// Returns the default Encoding, the Latin1.
+    fn default() -> Self {
+        Encoding::Latin1
+    }
+}
+
+/// Decodes a message, falling back to `encoding` if it is not valid UTF-8.
+///
+/// UTF-8 messages are borrowed, all others are copied.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{decode_lossy, Encoding};
+///
+/// let message = b"fix: handle na\xefve input";
+/// assert_eq!(decode_lossy(message, Encoding::Latin1), "fix: handle naïve input");
+/// ```
+pub fn decode_lossy(bytes: &[u8], encoding: Encoding) -> Cow<'_, str> {
+    match str::from_utf8(bytes) {
+        Ok(message) => Cow::Borrowed(message),
+        Err(_) => {
+            let mut decoded = String::new();
+            decode_into(bytes, encoding, &mut decoded);
+            Cow::Owned(decoded)
+        }
+    }
+}
+
+/// Decodes a message that is not valid UTF-8 into `buf`, replacing its
+/// contents.
+pub(crate) fn decode_into(bytes: &[u8], encoding: Encoding, buf: &mut String) {
+    buf.clear();
+    match encoding {
+        Encoding::Latin1 => buf.extend(bytes.iter().map(|&byte| char::from(byte))),
+        Encoding::Utf8Lossy => buf.push_str(&String::from_utf8_lossy(bytes)),
+    }
+}
@@ -0,0 +1,96 @@
+//! [`miette::Diagnostic`] implementations for parse errors and lint
+//! violations.
+//!
+//! Neither keeps the message it was found in, so the message is attached to
+//! the diagnostic with [`miette::Report::with_source_code`]. The labels point
+//! at the span of the error or violation in it.
+
+use crate::{
+    lint::{self, Violation},
+    ParseError, ParseErrorKind, Span,
+};
+use miette::{Diagnostic, LabeledSpan, SourceSpan};
+use std::{fmt::Display, iter};
+
+impl Diagnostic for ParseError {
+    /// Returns the kind of the error in kebab case, like `missing-separator`.
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(code(self.kind())))
+    }
+
+    /// Returns a label explaining the error at its span.
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let label = LabeledSpan::new_primary_with_span(
+            Some(self.kind().to_string()),
+            source_span(self.span()),
+        );
+        Some(Box::new(iter::once(label)))
+    }
+}
+
+impl Diagnostic for Violation {
+    /// Returns the identifier of the violated rule, like `subject-full-stop`.
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(self.rule()))
+    }
+
+    fn severity(&self) -> Option<miette::Severity> {
+        Some(match self.severity() {
+            lint::Severity::Warning => miette::Severity::Warning,
+            lint::Severity::Off | lint::Severity::Error => miette::Severity::Error,
+        })
+    }
+
+    /// Returns how the fix of the violation changes the message, if it has
+    /// one.
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        let fix = self.fix()?;
+        let help = if fix.replacement().is_empty() {
+            "remove it".to_string()
+        } else if fix.span().is_empty() {
+            format!("insert `{}`", fix.replacement())
+        } else {
+            format!("replace it with `{}`", fix.replacement())
+        };
+        Some(Box::new(help))
+    }
+
+    /// Returns a label with the message of the violation at its span and, if
+    /// the fix changes another span, an unlabeled one at that span.
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let label = LabeledSpan::new_primary_with_span(
+            Some(self.message().to_string()),
+            source_span(self.span()),
+        );
+        let fix = self
+            .fix()
+            .map(|fix| fix.span())
+            .filter(|&span| span != self.span())
+            .map(|span| LabeledSpan::new_with_span(None, source_span(span)));
+        Some(Box::new(iter::once(label).chain(fix)))
+    }
+}
+
+/// Converts a span into the one of miette.
+fn source_span(span: Span) -> SourceSpan {
+    SourceSpan::from(span.start..span.end)
+}
+
+/// Returns the diagnostic code of a kind of parse error.
+fn code(kind: ParseErrorKind) -> &'static str {
+    match kind {
+        ParseErrorKind::MissingType => "missing-type",
+        ParseErrorKind::InvalidType => "invalid-type",
+        ParseErrorKind::InvalidScope => "invalid-scope",
+        ParseErrorKind::MisplacedBreakingMarker => "misplaced-breaking-marker",
+        ParseErrorKind::UnsupportedBreakingMarker => "unsupported-breaking-marker",
+        ParseErrorKind::MissingSeparator => "missing-separator",
+        ParseErrorKind::MissingDescription => "missing-description",
+        ParseErrorKind::MissingBlankLine => "missing-blank-line",
+        ParseErrorKind::MalformedFooter => "malformed-footer",
+        ParseErrorKind::InvalidFooterToken => "invalid-footer-token",
+        ParseErrorKind::UnexpectedWhitespace => "unexpected-whitespace",
+        ParseErrorKind::UnknownFooterSeparator => "unknown-footer-separator",
+        ParseErrorKind::UnexpectedNewline => "unexpected-newline",
+    }
+}
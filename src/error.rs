@@ -0,0 +1,183 @@
+use crate::Span;
+use std::{error::Error, fmt};
+
+/// The different kinds of errors that can occur while parsing.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ParseErrorKind {
+    /// The header does not start with a type.
+    MissingType,
+    /// The type contains characters that are not allowed, e.g. whitespace or,
+    /// when parsing strictly, uppercase letters or a type that the convention
+    /// does not allow.
+    InvalidType,
+    /// The scope is empty, not terminated or contains invalid characters.
+    InvalidScope,
+    /// The `!` breaking change marker is placed in front of the scope instead
+    /// of the colon. Only reported when parsing strictly.
+    MisplacedBreakingMarker,
+    /// The `!` breaking change marker is used although the convention or the
+    /// revision of the specification does not allow it. Only reported when
+    /// parsing strictly.
+    UnsupportedBreakingMarker,
+    /// The type and scope are not followed by the `:<space>` separator.
+    MissingSeparator,
+    /// The header has no description after the separator.
+    MissingDescription,
+    /// The header is not followed by an empty line.
+    MissingBlankLine,
+    /// A line in the footer section is not a valid footer.
+    MalformedFooter,
+    /// A footer token contains spaces instead of hyphens. Only reported when
+    /// parsing strictly.
+    InvalidFooterToken,
+    /// A component starts or ends with whitespace. Only reported when parsing
+    /// strictly.
+    UnexpectedWhitespace,
+    /// A string is not one of the known footer separators.
+    UnknownFooterSeparator,
+    /// A component that has to fit on its line, like the description or a
+    /// footer token, contains a line break. Only reported when building a
+    /// commit with a [`CommitBuilder`](crate::CommitBuilder).
+    UnexpectedNewline,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            ParseErrorKind::MissingType => "missing commit type",
+            ParseErrorKind::InvalidType => "the commit type must be a single lowercase word",
+            ParseErrorKind::InvalidScope => "the scope must be a non-empty noun in parentheses",
+            ParseErrorKind::MisplacedBreakingMarker => {
+                "the breaking change marker must directly precede the colon"
+            }
+            ParseErrorKind::UnsupportedBreakingMarker => {
+                "the breaking change marker is not supported"
+            }
+            ParseErrorKind::MissingSeparator => "the type must be followed by a colon and a space",
+            ParseErrorKind::MissingDescription => "missing description",
+            ParseErrorKind::MissingBlankLine => "the header must be followed by an empty line",
+            ParseErrorKind::MalformedFooter => "footer is not a token followed by a value",
+            ParseErrorKind::InvalidFooterToken => "footer tokens must use `-` instead of spaces",
+            ParseErrorKind::UnexpectedWhitespace => "unexpected leading or trailing whitespace",
+            ParseErrorKind::UnknownFooterSeparator => "footer separator not recognized",
+            ParseErrorKind::UnexpectedNewline => "unexpected line break",
+        };
+        write!(f, "{}", msg)
+    }
+}
+
+/// An error returned when a commit message could not be parsed.
+///
+/// Besides its kind, the error records where in the input the problem was
+/// found, both as a byte range and as a line and column.
+///
+/// With the `miette` feature, it implements `miette::Diagnostic`, labeling
+/// its span of the message, which is attached with
+/// `Report::with_source_code`. The feature requires Rust 1.70.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "miette")]
+/// # {
+/// use conventional_commits_types::Commit;
+/// use miette::{Diagnostic, Report};
+///
+/// let message = "feat(parser: add a parser";
+/// let error = Commit::parse(message).unwrap_err();
+/// assert_eq!(error.code().unwrap().to_string(), "invalid-scope");
+///
+/// let label = error.labels().unwrap().next().unwrap();
+/// assert_eq!(label.label(), Some("the scope must be a non-empty noun in parentheses"));
+///
+/// let report = Report::new(error).with_source_code(message);
+/// assert!(report.source_code().is_some());
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    span: Span,
+    line: usize,
+    column: usize,
+}
+
+impl ParseError {
+    /// Creates an error of the given kind, located at the start of the input.
+    pub fn new(kind: ParseErrorKind) -> Self {
+        Self {
+            kind,
+            span: Span::default(),
+            line: 1,
+            column: 1,
+        }
+    }
+
+    /// Creates an error of the given kind that covers `span` of `input`.
+    pub fn at(kind: ParseErrorKind, input: &str, span: Span) -> Self {
+        let (line, column) = location(input, span.start);
+        Self {
+            kind,
+            span,
+            line,
+            column,
+        }
+    }
+
+    /// Returns the kind of the error.
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte range of the input that caused the error.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Returns the byte offset at which the error occurred.
+    pub fn offset(&self) -> usize {
+        self.span.start
+    }
+
+    /// Returns the line at which the error occurred, starting at 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column at which the error occurred, starting at 1.
+    ///
+    /// Columns are counted in characters, not bytes.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl From<ParseErrorKind> for ParseError {
+    fn from(kind: ParseErrorKind) -> Self {
+        Self::new(kind)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Returns the line and column of `offset` in `input`, both starting at 1.
+///
+/// Columns are counted in characters, not bytes.
+pub(crate) fn location(input: &str, offset: usize) -> (usize, usize) {
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
@@ -0,0 +1,148 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "schemars")]
+use std::borrow::Cow;
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+    hash::{Hash, Hasher},
+    iter::FromIterator,
+};
+
+/// Extra data that tools attach to a commit, like the number of its pull
+/// request or the status of its CI run.
+///
+/// Extensions are kept when a commit is serialized with serde or converted
+/// into a [`CommitOwned`](crate::CommitOwned) or a
+/// [`CommitCow`](crate::CommitCow), but they are not part of the message.
+/// Formatting a commit ignores them, and they always compare and hash as
+/// equal, so that commits which only differ in their extensions are equal.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::Commit;
+///
+/// let mut commit = Commit::parse("feat: add extensions").unwrap();
+/// commit.extensions.insert("pull-request", "42");
+/// assert_eq!(commit.extensions.get("pull-request"), Some("42"));
+/// assert_eq!(commit.to_string(), "feat: add extensions");
+/// assert_eq!(commit, Commit::parse("feat: add extensions").unwrap());
+///
+/// # #[cfg(feature = "serde")]
+/// # {
+/// let json = serde_json::to_string(&commit).unwrap();
+/// let deserialized: Commit<'_> = serde_json::from_str(&json).unwrap();
+/// assert_eq!(deserialized.extensions.get("pull-request"), Some("42"));
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Extensions(Option<BTreeMap<String, String>>);
+
+impl Extensions {
+    /// Creates empty extensions.
+    pub const fn new() -> Self {
+        Self(None)
+    }
+
+    /// Returns the value of the given key.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.as_ref()?.get(key).map(String::as_str)
+    }
+
+    /// Sets the value of the given key, returning the previous one.
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) -> Option<String> {
+        self.0
+            .get_or_insert_with(BTreeMap::new)
+            .insert(key.into(), value.into())
+    }
+
+    /// Removes the given key, returning its value.
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        self.0.as_mut()?.remove(key)
+    }
+
+    /// Returns the number of keys.
+    pub fn len(&self) -> usize {
+        self.0.as_ref().map_or(0, BTreeMap::len)
+    }
+
+    /// Returns `true` if there are no keys.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the keys and values, sorted by key.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .flatten()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for Extensions {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut extensions = Self::new();
+        for (key, value) in iter {
+            extensions.insert(key, value);
+        }
+        extensions
+    }
+}
+
+impl PartialEq for Extensions {
+    /// Returns `true`, since extensions are not part of the commit.
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for Extensions {}
+
+impl PartialOrd for Extensions {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Extensions {
+    /// Returns [`Ordering::Equal`], since extensions are not part of the
+    /// commit.
+    fn cmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Hash for Extensions {
+    /// Hashes nothing, since extensions are not part of the commit.
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Extensions {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Extensions {
+    fn schema_name() -> Cow<'static, str> {
+        "Extensions".into()
+    }
+
+    /// Describes the extensions as a map of strings, like they are
+    /// serialized.
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        BTreeMap::<String, String>::json_schema(generator)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Extensions {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let map = BTreeMap::deserialize(deserializer)?;
+        Ok(Self(if map.is_empty() { None } else { Some(map) }))
+    }
+}
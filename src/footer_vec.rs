@@ -0,0 +1,288 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "schemars")]
+use std::borrow::Cow;
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    iter::FromIterator,
+    mem,
+    ops::{Deref, DerefMut},
+    slice, vec,
+};
+
+/// A vector that stores a single element inline.
+///
+/// Most commits have no footer or a single one, so the footers of a commit
+/// and their spans are stored in a `FooterVec`: like a [`Vec`], but only
+/// allocating once a second element is pushed. It dereferences to a slice
+/// and compares, hashes and serializes like one.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{Commit, FooterVec};
+///
+/// let commit = Commit::parse("fix: handle empty input\n\nRefs: #7").unwrap();
+/// assert_eq!(commit.footer.len(), 1);
+/// assert_eq!(commit.footer[0].value, "#7");
+///
+/// let mut numbers: FooterVec<u32> = vec![3, 1].into();
+/// numbers.push(2);
+/// numbers.sort();
+/// assert_eq!(*numbers, [1, 2, 3]);
+/// ```
+#[derive(Clone)]
+pub struct FooterVec<T>(Repr<T>);
+
+#[derive(Clone)]
+enum Repr<T> {
+    Empty,
+    One(T),
+    Many(Vec<T>),
+}
+
+impl<T> FooterVec<T> {
+    /// Creates an empty vector, which does not allocate.
+    pub const fn new() -> Self {
+        Self(Repr::Empty)
+    }
+
+    /// Appends an element.
+    pub fn push(&mut self, value: T) {
+        self.0 = match mem::replace(&mut self.0, Repr::Empty) {
+            Repr::Empty => Repr::One(value),
+            Repr::One(first) => Repr::Many(vec![first, value]),
+            Repr::Many(mut values) => {
+                values.push(value);
+                Repr::Many(values)
+            }
+        };
+    }
+
+    /// Removes the last element and returns it.
+    pub fn pop(&mut self) -> Option<T> {
+        match &mut self.0 {
+            Repr::Empty => None,
+            Repr::One(_) => match mem::replace(&mut self.0, Repr::Empty) {
+                Repr::One(value) => Some(value),
+                _ => unreachable!(),
+            },
+            Repr::Many(values) => values.pop(),
+        }
+    }
+
+    /// Inserts an element at `index`, shifting the following ones.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the length.
+    pub fn insert(&mut self, index: usize, value: T) {
+        assert!(index <= self.len(), "insertion index is out of bounds");
+        let mut values = self.take_vec();
+        values.insert(index, value);
+        *self = values.into();
+    }
+
+    /// Removes the element at `index`, shifting the following ones.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len(), "removal index is out of bounds");
+        let mut values = self.take_vec();
+        let value = values.remove(index);
+        *self = values.into();
+        value
+    }
+
+    /// Keeps only the elements for which `f` returns `true`.
+    pub fn retain(&mut self, f: impl FnMut(&T) -> bool) {
+        let mut values = self.take_vec();
+        values.retain(f);
+        *self = values.into();
+    }
+
+    /// Removes all elements.
+    pub fn clear(&mut self) {
+        self.0 = Repr::Empty;
+    }
+
+    /// Converts the vector into a [`Vec`].
+    pub fn into_vec(self) -> Vec<T> {
+        match self.0 {
+            Repr::Empty => Vec::new(),
+            Repr::One(value) => vec![value],
+            Repr::Many(values) => values,
+        }
+    }
+
+    /// Moves the elements into a [`Vec`], leaving the vector empty.
+    fn take_vec(&mut self) -> Vec<T> {
+        mem::take(self).into_vec()
+    }
+}
+
+impl<T> Default for FooterVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for FooterVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        match &self.0 {
+            Repr::Empty => &[],
+            Repr::One(value) => slice::from_ref(value),
+            Repr::Many(values) => values,
+        }
+    }
+}
+
+impl<T> DerefMut for FooterVec<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        match &mut self.0 {
+            Repr::Empty => &mut [],
+            Repr::One(value) => slice::from_mut(value),
+            Repr::Many(values) => values,
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for FooterVec<T> {
+    /// Converts a vector, keeping a single element inline.
+    fn from(mut values: Vec<T>) -> Self {
+        match values.len() {
+            0 => Self::new(),
+            1 => Self(Repr::One(values.pop().expect("the vector has an element"))),
+            _ => Self(Repr::Many(values)),
+        }
+    }
+}
+
+impl<T> From<FooterVec<T>> for Vec<T> {
+    fn from(values: FooterVec<T>) -> Self {
+        values.into_vec()
+    }
+}
+
+impl<T> FromIterator<T> for FooterVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut values = Self::new();
+        values.extend(iter);
+        values
+    }
+}
+
+impl<T> Extend<T> for FooterVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T> IntoIterator for FooterVec<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_vec().into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a FooterVec<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut FooterVec<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for FooterVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: PartialEq> PartialEq for FooterVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: PartialEq> PartialEq<[T]> for FooterVec<T> {
+    fn eq(&self, other: &[T]) -> bool {
+        **self == *other
+    }
+}
+
+impl<T: PartialEq> PartialEq<Vec<T>> for FooterVec<T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Eq> Eq for FooterVec<T> {}
+
+impl<T: PartialOrd> PartialOrd for FooterVec<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T: Ord> Ord for FooterVec<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<T: Hash> Hash for FooterVec<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Serialize> Serialize for FooterVec<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl<T: schemars::JsonSchema> schemars::JsonSchema for FooterVec<T> {
+    fn schema_name() -> Cow<'static, str> {
+        Vec::<T>::schema_name()
+    }
+
+    fn inline_schema() -> bool {
+        Vec::<T>::inline_schema()
+    }
+
+    /// Describes the vector as an array, like it is serialized.
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        Vec::<T>::json_schema(generator)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for FooterVec<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(Self::from)
+    }
+}
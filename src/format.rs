@@ -0,0 +1,569 @@
+use crate::{
+    parser, CaseSensitivity, Commit, Extensions, Footer, FooterSeparator, FooterVec, Header,
+    LineEnding, ParseOptions, BREAKING_CHANGE_MARKER, BREAKING_CHANGE_TOKEN,
+    BREAKING_CHANGE_TOKEN_ALIAS, SCOPE_SEPARATORS, SEPARATOR_COLON, SIGNED_OFF_BY_TOKEN,
+};
+use std::fmt::{self, Write};
+
+/// How a breaking change is expressed when formatting a commit.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum BreakingStyle {
+    /// The `!` marker in front of the header's colon, `feat!: ...`.
+    Bang,
+    /// A `BREAKING CHANGE` footer.
+    Footer,
+    /// Both the `!` marker and a `BREAKING CHANGE` footer.
+    Both,
+}
+
+impl Default for BreakingStyle {
+    /// Returns the default BreakingStyle, the Bang.
+    fn default() -> Self {
+        BreakingStyle::Bang
+    }
+}
+
+/// The order in which the footers of a commit are formatted.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum FooterOrder {
+    /// The order of the commit's footers.
+    Original,
+    /// Breaking change footers first, then all others in their original
+    /// order.
+    BreakingChangeFirst,
+    /// Sorted by their tokens. Footers with the same token keep their order.
+    ByToken,
+}
+
+impl Default for FooterOrder {
+    /// Returns the default FooterOrder, the Original.
+    fn default() -> Self {
+        FooterOrder::Original
+    }
+}
+
+/// The column at which git conventionally wraps the body of a message.
+pub const DEFAULT_WRAP_WIDTH: usize = 72;
+
+/// Re-wraps `text` so that no line is longer than `width` characters, unless
+/// a single word is.
+///
+/// Lines are broken at whitespace, and existing line breaks are kept.
+/// Indented lines, like code blocks, are left as they are, and the
+/// continuation lines of list items starting with `- ` or `* ` are indented
+/// to line up with the item's text. Use [`DEFAULT_WRAP_WIDTH`] for the
+/// classic git formatting.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::wrap;
+///
+/// assert_eq!(wrap("a short line\n- and a list item", 10), "a short\nline\n- and a\n  list\n  item");
+/// ```
+pub fn wrap(text: &str, width: usize) -> String {
+    let mut wrapped = String::with_capacity(text.len());
+    write_lines(&mut wrapped, text, Some(width), LineEnding::Lf.as_str())
+        .expect("writing to a String cannot fail");
+    wrapped
+}
+
+/// The order into which [`Commit::normalize_footers`] sorts footers.
+///
+/// Footers whose token is in `first` come first, in the order of the list,
+/// and those whose token is in `last` come last. All others stay in between
+/// in their original order. Tokens are compared case-insensitively.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{Commit, FooterNormalization};
+///
+/// let message = "fix: a\n\nSigned-off-by: Jane <j@x.org>\nRefs: #1\nRefs: #1\nBREAKING CHANGE: b";
+/// let mut commit = Commit::parse(message).unwrap();
+/// commit.normalize_footers(&FooterNormalization::default());
+/// let tokens: Vec<_> = commit.footer.iter().map(|footer| footer.token).collect();
+/// assert_eq!(tokens, ["BREAKING CHANGE", "Refs", "Signed-off-by"]);
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FooterNormalization {
+    /// The tokens of the footers that come first.
+    pub first: Vec<String>,
+    /// The tokens of the footers that come last.
+    pub last: Vec<String>,
+    /// Whether only the first of several footers with the same token and
+    /// value is kept.
+    pub deduplicate: bool,
+}
+
+impl Default for FooterNormalization {
+    /// Returns the default FooterNormalization, which puts breaking changes
+    /// first and sign-offs last and removes duplicates.
+    fn default() -> Self {
+        Self {
+            first: vec![
+                BREAKING_CHANGE_TOKEN.to_string(),
+                BREAKING_CHANGE_TOKEN_ALIAS.to_string(),
+            ],
+            last: vec![SIGNED_OFF_BY_TOKEN.to_string()],
+            deduplicate: true,
+        }
+    }
+}
+
+/// Sorts and deduplicates footers as configured by `options`.
+pub(crate) fn normalize_footers(
+    footers: &mut FooterVec<Footer<'_>>,
+    options: &FooterNormalization,
+) {
+    let position = |tokens: &[String], footer: &Footer<'_>| {
+        tokens
+            .iter()
+            .position(|token| footer.matches_token(token, CaseSensitivity::Insensitive))
+    };
+    footers.sort_by_key(|footer| {
+        match (
+            position(&options.first, footer),
+            position(&options.last, footer),
+        ) {
+            (Some(idx), _) => (0, idx),
+            (None, Some(idx)) => (2, idx),
+            (None, None) => (1, 0),
+        }
+    });
+
+    if options.deduplicate {
+        let mut idx = 0;
+        while idx < footers.len() {
+            let footer = &footers[idx];
+            let duplicate = footers[..idx].iter().any(|earlier| {
+                earlier.matches_token(footer.token, CaseSensitivity::Insensitive)
+                    && earlier.value == footer.value
+            });
+            if duplicate {
+                footers.remove(idx);
+            } else {
+                idx += 1;
+            }
+        }
+    }
+}
+
+/// Options that control how commits are formatted as messages.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{BreakingStyle, Commit, FormatOptions};
+///
+/// let commit = Commit::parse("feat!: drop the old API\n\nRefs: #1").unwrap();
+/// let options = FormatOptions {
+///     breaking_style: BreakingStyle::Footer,
+///     ..FormatOptions::default()
+/// };
+/// assert_eq!(
+///     commit.format(&options),
+///     "feat: drop the old API\n\nBREAKING CHANGE: drop the old API\nRefs: #1"
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FormatOptions {
+    /// How a breaking change is expressed.
+    pub breaking_style: BreakingStyle,
+    /// The number of characters after which the lines of the body are
+    /// wrapped, as done by [`wrap`]. The body is written as is if this is
+    /// `None`.
+    pub wrap_width: Option<usize>,
+    /// The order of the footers.
+    pub footer_order: FooterOrder,
+    /// Whether the footers are separated by empty lines instead of single
+    /// line breaks.
+    pub blank_lines_between_footers: bool,
+    /// The line ending that is written after each line.
+    pub line_ending: LineEnding,
+}
+
+/// Writes `type(scope)!: description`, with the `!` only if `marker` is set.
+///
+/// An autosquash prefix and a gitmoji of the header are written in front of
+/// it.
+pub(crate) fn write_header<W: Write>(w: &mut W, header: &Header<'_>, marker: bool) -> fmt::Result {
+    if let Some(autosquash) = header.autosquash {
+        w.write_str(autosquash.prefix())?;
+    }
+    if let Some(gitmoji) = header.gitmoji {
+        write!(w, "{} ", gitmoji)?;
+    }
+    w.write_str(header.ty)?;
+    if let Some(scope) = header.scope {
+        write!(w, "({})", scope)?;
+    }
+    if marker {
+        w.write_char(BREAKING_CHANGE_MARKER)?;
+    }
+    write!(w, "{}{}", SEPARATOR_COLON, header.desc)
+}
+
+/// Writes `token`, the separator and the value of a footer, ending the lines
+/// of a multi-line value with `newline`.
+pub(crate) fn write_footer<W: Write>(w: &mut W, footer: &Footer<'_>, newline: &str) -> fmt::Result {
+    write!(w, "{}{}", footer.token, footer.separator)?;
+    write_lines(w, footer.value, None, newline)
+}
+
+/// Writes a whole commit message as configured by `options`.
+///
+/// If a `BREAKING CHANGE` footer is required but missing, one is added with
+/// the description as its value.
+pub(crate) fn write_commit<W: Write>(
+    w: &mut W,
+    commit: &Commit<'_>,
+    options: &FormatOptions,
+) -> fmt::Result {
+    let newline = options.line_ending.as_str();
+    let style = options.breaking_style;
+    let breaking = commit.is_breaking_change;
+    write_header(
+        w,
+        &commit.header(),
+        breaking && style != BreakingStyle::Footer,
+    )?;
+
+    if let Some(body) = commit.body.filter(|body| !body.is_empty()) {
+        write!(w, "{}{}", newline, newline)?;
+        write_lines(w, body, options.wrap_width, newline)?;
+    }
+
+    let mut footers = Vec::with_capacity(commit.footer.len() + 1);
+    let add_footer = breaking
+        && style != BreakingStyle::Bang
+        && !commit.footer.iter().any(Footer::is_breaking_change);
+    if add_footer {
+        footers.push(Footer::from(
+            BREAKING_CHANGE_TOKEN,
+            FooterSeparator::ColonSpace,
+            commit.desc,
+        ));
+    }
+    footers.extend(commit.footer.iter().cloned());
+    match options.footer_order {
+        FooterOrder::Original => {}
+        FooterOrder::BreakingChangeFirst => {
+            footers.sort_by_key(|footer| !footer.is_breaking_change())
+        }
+        FooterOrder::ByToken => footers.sort_by_key(|footer| footer.token),
+    }
+
+    for (idx, footer) in footers.iter().enumerate() {
+        if idx == 0 || options.blank_lines_between_footers {
+            w.write_str(newline)?;
+        }
+        w.write_str(newline)?;
+        write_footer(w, footer, newline)?;
+    }
+    Ok(())
+}
+
+/// Returns the canonical message of a commit parsed with `options`.
+///
+/// The type is lowercased, runs of whitespace in the description are
+/// collapsed, whitespace around the scope and its parts is removed, as is
+/// trailing whitespace of the body and footer lines. Breaking changes are
+/// marked with `!` if the convention and revision of `options` allow it and
+/// with a footer otherwise. Footers that mark breaking changes under
+/// `options` use [`BREAKING_CHANGE_TOKEN`], other tokens have their spaces
+/// replaced with `-` and custom separators are replaced with `: `.
+pub(crate) fn normalize(commit: &Commit<'_>, options: &ParseOptions) -> String {
+    let ty = commit.ty.trim().to_lowercase();
+    let desc = commit.desc.split_whitespace().collect::<Vec<_>>().join(" ");
+    let scope = commit
+        .scope
+        .map(normalize_scope)
+        .filter(|scope| !scope.is_empty());
+    let body = commit.body.map(trim_lines);
+    let mut footers: Vec<_> = commit
+        .footer
+        .iter()
+        .map(|footer| {
+            let token = if parser::is_breaking_change_token(footer.token, options) {
+                BREAKING_CHANGE_TOKEN.to_string()
+            } else {
+                footer
+                    .suggested_token()
+                    .unwrap_or_else(|| footer.token.to_string())
+            };
+            let separator = match footer.separator {
+                FooterSeparator::Custom(_) => FooterSeparator::ColonSpace,
+                separator => separator,
+            };
+            (token, separator, trim_lines(footer.value))
+        })
+        .collect();
+
+    let style = if options.convention.breaking_style() == BreakingStyle::Bang
+        && options.spec_version.allows_breaking_marker()
+    {
+        BreakingStyle::Bang
+    } else {
+        BreakingStyle::Footer
+    };
+    // The footer is added here, as `write_commit` would not add one next to
+    // a `BREAKING-CHANGE` footer that does not mark a breaking change.
+    let add_footer = commit.is_breaking_change
+        && style == BreakingStyle::Footer
+        && !footers
+            .iter()
+            .any(|(token, _, _)| token == BREAKING_CHANGE_TOKEN);
+    if add_footer {
+        footers.insert(
+            0,
+            (
+                BREAKING_CHANGE_TOKEN.to_string(),
+                FooterSeparator::ColonSpace,
+                desc.clone(),
+            ),
+        );
+    }
+
+    let normalized = Commit {
+        autosquash: commit.autosquash,
+        body: body.as_deref().filter(|body| !body.is_empty()),
+        desc: &desc,
+        extensions: Extensions::new(),
+        footer: footers
+            .iter()
+            .map(|(token, separator, value)| Footer::from(token, *separator, value))
+            .collect(),
+        gitmoji: commit.gitmoji,
+        has_breaking_marker: commit.has_breaking_marker,
+        is_breaking_change: commit.is_breaking_change,
+        scope: scope.as_deref(),
+        ty: &ty,
+    };
+    let options = FormatOptions {
+        breaking_style: style,
+        ..FormatOptions::default()
+    };
+    let mut message = String::new();
+    write_commit(&mut message, &normalized, &options).expect("writing to a String cannot fail");
+    message
+}
+
+/// Removes the whitespace around a scope and each of its parts, as well as
+/// empty parts, like the one in `a,,b`.
+///
+/// Each remaining part is preceded by the separator in front of it.
+fn normalize_scope(scope: &str) -> String {
+    let mut normalized = String::with_capacity(scope.len());
+    let mut push = |separator: &str, part: &str| {
+        let part = part.trim();
+        if !part.is_empty() {
+            if !normalized.is_empty() {
+                normalized.push_str(separator);
+            }
+            normalized.push_str(part);
+        }
+    };
+    let mut start = 0;
+    let mut separator = "";
+    for (idx, next) in scope.match_indices(SCOPE_SEPARATORS) {
+        push(separator, &scope[start..idx]);
+        separator = next;
+        start = idx + next.len();
+    }
+    push(separator, &scope[start..]);
+    normalized
+}
+
+/// Removes trailing whitespace from each line of `text` as well as blank
+/// lines at its start and end.
+fn trim_lines(text: &str) -> String {
+    let lines: Vec<_> = text.lines().map(str::trim_end).collect();
+    lines.join("\n").trim_matches('\n').to_string()
+}
+
+/// Writes the lines of `text`, ending each but the last one with `newline`
+/// and wrapping them after `width` characters if given.
+pub(crate) fn write_lines<W: Write>(
+    w: &mut W,
+    text: &str,
+    width: Option<usize>,
+    newline: &str,
+) -> fmt::Result {
+    for (idx, line) in text.split('\n').map(parser::strip_cr).enumerate() {
+        if idx > 0 {
+            w.write_str(newline)?;
+        }
+        match width {
+            Some(width) => write_wrapped(w, line, width, newline)?,
+            None => w.write_str(line)?,
+        }
+    }
+    Ok(())
+}
+
+/// Writes a single line, breaking it at whitespace so that no line is longer
+/// than `width` characters, unless a single word is.
+///
+/// Indented lines, like code blocks, are never wrapped. The continuation
+/// lines of list items are indented to line up with the item's text.
+fn write_wrapped<W: Write>(w: &mut W, line: &str, width: usize, newline: &str) -> fmt::Result {
+    if line.chars().count() <= width || line.starts_with(char::is_whitespace) {
+        return w.write_str(line);
+    }
+    let indent = if line.starts_with("- ") || line.starts_with("* ") {
+        "  "
+    } else {
+        ""
+    };
+
+    let mut column = 0;
+    for (idx, word) in line.split_whitespace().enumerate() {
+        let len = word.chars().count();
+        if idx > 0 {
+            if column + 1 + len > width {
+                write!(w, "{}{}", newline, indent)?;
+                column = indent.len();
+            } else {
+                w.write_char(' ')?;
+                column += 1;
+            }
+        }
+        w.write_str(word)?;
+        column += len;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Convention;
+
+    /// Normalizes `message` parsed leniently with `options`, and parses the
+    /// canonical message strictly.
+    fn round_trip(message: &str, options: ParseOptions) -> Result<String, crate::ParseError> {
+        let lenient = ParseOptions {
+            strict: false,
+            ..options.clone()
+        };
+        let canonical = Commit::parse_with(message, &lenient)
+            .unwrap()
+            .normalize_with(&lenient);
+        Commit::parse_with(&canonical, &options)?;
+        Ok(canonical)
+    }
+
+    #[test]
+    fn empty_scope_parts_are_dropped() {
+        assert_eq!(
+            round_trip("feat(a,,b): x", ParseOptions::default()).unwrap(),
+            "feat(a,b): x"
+        );
+        assert_eq!(
+            round_trip("feat( , ): x", ParseOptions::default()).unwrap(),
+            "feat: x"
+        );
+        assert_eq!(normalize_scope(" a /, b,"), "a,b");
+    }
+
+    #[test]
+    fn types_are_not_replaced() {
+        let options = ParseOptions {
+            convention: Convention::Angular,
+            ..ParseOptions::default()
+        };
+        let error = round_trip("chore: x", options).unwrap_err();
+        assert_eq!(error.kind(), crate::ParseErrorKind::InvalidType);
+    }
+
+    fn normalized(message: &str, options: &ParseOptions) -> String {
+        normalize(&Commit::parse_with(message, options).unwrap(), options)
+    }
+
+    #[test]
+    fn whitespace_and_case_are_normalized() {
+        let options = ParseOptions::lenient();
+        assert_eq!(
+            normalized(
+                " FEAT( api , cli ):  add   a\tparser \n\n\nA body.  \n\n",
+                &options
+            ),
+            "feat(api,cli): add a parser\n\nA body."
+        );
+        assert_eq!(
+            normalized(
+                "fix: x\n\n\n  indented  \n\n\nlast\t\n\nRefs: #1  ",
+                &options
+            ),
+            "fix: x\n\n  indented\n\n\nlast\n\nRefs: #1"
+        );
+    }
+
+    #[test]
+    fn footers_are_normalized() {
+        let options = ParseOptions {
+            footer_separators: vec![": ".to_string(), " #".to_string(), " = ".to_string()],
+            ..ParseOptions::lenient()
+        };
+        assert_eq!(
+            normalized(
+                "fix: x\n\nsigned off by: Jane\nAcked-by = Bob\nCloses #1",
+                &options
+            ),
+            "fix: x\n\nsigned-off-by: Jane\nAcked-by: Bob\nCloses #1"
+        );
+    }
+
+    #[test]
+    fn breaking_changes_follow_the_convention() {
+        let options = ParseOptions::lenient();
+        assert_eq!(normalized("feat!: drop v1", &options), "feat!: drop v1");
+        assert_eq!(
+            normalized("feat: drop v1\n\nBREAKING-CHANGE: use v2", &options),
+            "feat!: drop v1\n\nBREAKING CHANGE: use v2"
+        );
+
+        let angular = ParseOptions {
+            convention: Convention::Angular,
+            ..ParseOptions::lenient()
+        };
+        assert_eq!(
+            normalized("feat!: drop v1", &angular),
+            "feat: drop v1\n\nBREAKING CHANGE: drop v1"
+        );
+        assert_eq!(
+            normalized("feat: x\n\nBREAKING-CHANGE: y", &angular),
+            "feat: x\n\nBREAKING-CHANGE: y"
+        );
+
+        let beta = ParseOptions {
+            spec_version: crate::SpecVersion::V1_0_0Beta3,
+            ..ParseOptions::lenient()
+        };
+        assert_eq!(
+            normalized("feat: x\n\nBREAKING CHANGE: y", &beta),
+            "feat: x\n\nBREAKING CHANGE: y"
+        );
+    }
+
+    #[test]
+    fn normalizing_is_idempotent() {
+        let strict = ParseOptions {
+            autosquash: true,
+            ..ParseOptions::default()
+        };
+        let options = ParseOptions {
+            strict: false,
+            ..strict.clone()
+        };
+        for message in &[
+            "Feat(a, b)!:  x  \n\nbody\n\nBREAKING-CHANGE: y\nRefs #1",
+            "fixup! fix: x",
+            "docs: x\n\nIn short: it works",
+        ] {
+            let once = normalized(message, &options);
+            assert_eq!(normalized(&once, &strict), once, "{:?}", message);
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! Extended grapheme clusters, the characters a reader perceives as one.
+//!
+//! With the `unicode-segmentation` feature, clusters are segmented as
+//! specified by Unicode. Otherwise they are approximated: combining marks of
+//! common scripts, variation selectors, skin tone modifiers, emoji joined
+//! with zero width joiners, flags and `\r\n` are kept together, but rarer
+//! clusters, like the syllables of Hangul written with separate jamo, are
+//! split.
+
+use std::borrow::Cow;
+#[cfg(feature = "unicode-segmentation")]
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The character appended to truncated text.
+const ELLIPSIS: char = '…';
+
+#[cfg(not(feature = "unicode-segmentation"))]
+/// The zero width joiner that glues emoji into sequences, like `👩‍💻`.
+const ZERO_WIDTH_JOINER: char = '\u{200d}';
+
+#[cfg(not(feature = "unicode-segmentation"))]
+/// Returns `true` if `c` is never the start of a cluster but extends the
+/// previous one, like a combining accent, a variation selector or a skin tone
+/// modifier.
+fn is_extending(c: char) -> bool {
+    match c {
+        '\u{0300}'..='\u{036f}'
+        | '\u{0483}'..='\u{0489}'
+        | '\u{0591}'..='\u{05bd}'
+        | '\u{0610}'..='\u{061a}'
+        | '\u{064b}'..='\u{065f}'
+        | '\u{0900}'..='\u{0903}'
+        | '\u{093a}'..='\u{094f}'
+        | '\u{0e31}'
+        | '\u{0e34}'..='\u{0e3a}'
+        | '\u{0e47}'..='\u{0e4e}'
+        | '\u{1ab0}'..='\u{1aff}'
+        | '\u{1dc0}'..='\u{1dff}'
+        | '\u{200c}'
+        | '\u{20d0}'..='\u{20ff}'
+        | '\u{3099}'..='\u{309a}'
+        | '\u{fe00}'..='\u{fe0f}'
+        | '\u{fe20}'..='\u{fe2f}'
+        | '\u{1f3fb}'..='\u{1f3ff}'
+        | '\u{e0020}'..='\u{e007f}'
+        | '\u{e0100}'..='\u{e01ef}' => true,
+        _ => false,
+    }
+}
+
+#[cfg(not(feature = "unicode-segmentation"))]
+/// Returns `true` if `c` is one of the regional indicators that form flags in
+/// pairs, like `🇩🇪`.
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1f1e6}'..='\u{1f1ff}').contains(&c)
+}
+
+/// Returns the byte offsets at which the clusters of `text` start.
+#[cfg(feature = "unicode-segmentation")]
+fn cluster_starts(text: &str) -> impl Iterator<Item = usize> + '_ {
+    text.grapheme_indices(true).map(|(idx, _)| idx)
+}
+
+/// Returns the byte offsets at which the approximated clusters of `text`
+/// start.
+#[cfg(not(feature = "unicode-segmentation"))]
+fn cluster_starts(text: &str) -> impl Iterator<Item = usize> + '_ {
+    let mut previous: Option<char> = None;
+    let mut regional_indicators = 0;
+    text.char_indices().filter_map(move |(idx, c)| {
+        let joined = previous == Some(ZERO_WIDTH_JOINER);
+        let paired = is_regional_indicator(c) && regional_indicators % 2 == 1;
+        let crlf = previous == Some('\r') && c == '\n';
+        if is_regional_indicator(c) {
+            regional_indicators += 1;
+        } else {
+            regional_indicators = 0;
+        }
+        let extends = previous.is_some()
+            && (is_extending(c) || c == ZERO_WIDTH_JOINER || joined || paired || crlf);
+        previous = Some(c);
+        if extends {
+            None
+        } else {
+            Some(idx)
+        }
+    })
+}
+
+/// Returns the number of clusters of `text`.
+pub(crate) fn count(text: &str) -> usize {
+    cluster_starts(text).count()
+}
+
+/// Returns the byte offset of the cluster at index `n` of `text`, or its
+/// length if it has no more than `n` clusters.
+pub(crate) fn offset(text: &str, n: usize) -> usize {
+    cluster_starts(text).nth(n).unwrap_or(text.len())
+}
+
+/// Truncates `text` to at most `max` clusters, replacing the end with an
+/// ellipsis if anything had to be cut.
+///
+/// The ellipsis counts as one of the `max` clusters, and whitespace in front
+/// of it is removed.
+pub(crate) fn truncate(text: &str, max: usize) -> Cow<'_, str> {
+    if max == 0 {
+        return Cow::Borrowed("");
+    }
+    let mut starts = cluster_starts(text).skip(max - 1);
+    let cut = match (starts.next(), starts.next()) {
+        (Some(cut), Some(_)) => cut,
+        _ => return Cow::Borrowed(text),
+    };
+    let mut truncated = text[..cut].trim_end().to_string();
+    truncated.push(ELLIPSIS);
+    Cow::Owned(truncated)
+}
@@ -0,0 +1,148 @@
+//! Rendering of commits as HTML, for example for release notes.
+//!
+//! Every component is wrapped in an element with a `commit-*` class, so that
+//! pages can style them as they like.
+
+use crate::{AsCommit, Commit};
+use std::fmt::{self, Write};
+
+/// Renders commits as a `<section class="commits">` with an `<article>` for
+/// each commit.
+///
+/// The commits can be in any representation, borrowed or owned.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{render_html, Commit};
+///
+/// let commits = [Commit::parse("feat: add <br>").unwrap()];
+/// assert_eq!(
+///     render_html(&commits),
+///     "<section class=\"commits\">\n\
+///      <article class=\"commit\"><header class=\"commit-header\">\
+///      <span class=\"commit-type\">feat</span>: \
+///      <span class=\"commit-description\">add &lt;br&gt;</span>\
+///      </header></article>\n\
+///      </section>"
+/// );
+/// ```
+pub fn render_html<I>(commits: I) -> String
+where
+    I: IntoIterator,
+    I::Item: AsCommit,
+{
+    let mut html = String::from("<section class=\"commits\">\n");
+    for commit in commits {
+        write_commit(&mut html, &commit.as_commit()).expect("writing to a String cannot fail");
+        html.push('\n');
+    }
+    html.push_str("</section>");
+    html
+}
+
+/// Writes a commit as an `<article class="commit">`.
+///
+/// Breaking changes additionally have the `commit-breaking` class. The header
+/// is a `<header>`, the paragraphs of the body are `<p>` elements of a
+/// `<div class="commit-body">` and the footers are the terms and
+/// descriptions of a `<dl class="commit-footers">`.
+pub(crate) fn write_commit<W: Write>(w: &mut W, commit: &Commit<'_>) -> fmt::Result {
+    if commit.is_breaking_change {
+        w.write_str("<article class=\"commit commit-breaking\">")?;
+    } else {
+        w.write_str("<article class=\"commit\">")?;
+    }
+
+    w.write_str("<header class=\"commit-header\">")?;
+    if let Some(gitmoji) = commit.gitmoji {
+        write_element(w, "span", "commit-gitmoji", gitmoji)?;
+        w.write_char(' ')?;
+    }
+    write_element(w, "span", "commit-type", commit.ty)?;
+    if let Some(scope) = commit.scope {
+        w.write_char('(')?;
+        write_element(w, "span", "commit-scope", scope)?;
+        w.write_char(')')?;
+    }
+    if commit.is_breaking_change {
+        write_element(w, "span", "commit-breaking-marker", "!")?;
+    }
+    w.write_str(": ")?;
+    write_element(w, "span", "commit-description", commit.desc)?;
+    w.write_str("</header>")?;
+
+    if let Some(body) = commit.body.filter(|body| !body.trim().is_empty()) {
+        w.write_str("<div class=\"commit-body\">")?;
+        for paragraph in paragraphs(body) {
+            w.write_str("<p>")?;
+            write_escaped(w, paragraph)?;
+            w.write_str("</p>")?;
+        }
+        w.write_str("</div>")?;
+    }
+
+    if !commit.footer.is_empty() {
+        w.write_str("<dl class=\"commit-footers\">")?;
+        for footer in &commit.footer {
+            let class = if footer.is_breaking_change() {
+                "commit-footer-token commit-breaking-change"
+            } else {
+                "commit-footer-token"
+            };
+            write_element(w, "dt", class, footer.token)?;
+            write_element(w, "dd", "commit-footer-value", footer.value)?;
+        }
+        w.write_str("</dl>")?;
+    }
+    w.write_str("</article>")
+}
+
+/// Returns the paragraphs of `text`, which are separated by blank lines.
+fn paragraphs(text: &str) -> Vec<&str> {
+    let mut paragraphs = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut offset = 0;
+    for line in text.split('\n') {
+        if line.trim().is_empty() {
+            if let Some(start) = start.take() {
+                paragraphs.push(text[start..offset].trim_end());
+            }
+        } else if start.is_none() {
+            start = Some(offset);
+        }
+        offset += line.len() + 1;
+    }
+    if let Some(start) = start {
+        paragraphs.push(text[start..].trim_end());
+    }
+    paragraphs
+}
+
+/// Writes `<tag class="class">text</tag>`, escaping the text.
+fn write_element<W: Write>(w: &mut W, tag: &str, class: &str, text: &str) -> fmt::Result {
+    write!(w, "<{} class=\"{}\">", tag, class)?;
+    write_escaped(w, text)?;
+    write!(w, "</{}>", tag)
+}
+
+/// Writes `text` with the characters that have a meaning in HTML replaced by
+/// their entities.
+fn write_escaped<W: Write>(w: &mut W, text: &str) -> fmt::Result {
+    let mut last = 0;
+    for (idx, c) in text.char_indices() {
+        let entity = match c {
+            '&' => "&amp;",
+            '<' => "&lt;",
+            '>' => "&gt;",
+            '"' => "&quot;",
+            '\'' => "&#39;",
+            '\r' => "",
+            _ => continue,
+        };
+        w.write_str(&text[last..idx])?;
+        w.write_str(entity)?;
+        last = idx + c.len_utf8();
+    }
+    w.write_str(&text[last..])
+}
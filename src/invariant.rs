@@ -0,0 +1,26 @@
+use std::{error::Error, fmt};
+
+/// A violated invariant of a commit, found by
+/// [`Commit::check_invariants`](crate::Commit::check_invariants).
+///
+/// Parsed commits and those built with the checked constructors and setters
+/// never violate them, but commits whose fields were set directly might.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum InvariantViolation {
+    /// The commit has a `BREAKING CHANGE` footer but is not marked as a
+    /// breaking change.
+    UnmarkedBreakingFooter,
+}
+
+impl fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            InvariantViolation::UnmarkedBreakingFooter => {
+                "the commit has a breaking change footer but is not a breaking change"
+            }
+        };
+        write!(f, "{}", msg)
+    }
+}
+
+impl Error for InvariantViolation {}
@@ -0,0 +1,107 @@
+use crate::Commit;
+
+/// Defines which issue keys, like the `PROJ-123` keys of Jira, are
+/// recognized.
+///
+/// By default, a key is a project key of at least two uppercase letters,
+/// digits or underscores that starts with a letter, followed by a `-` and
+/// the number of the issue.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{Commit, IssueKeyPattern};
+///
+/// let commit = Commit::parse("fix(PROJ-12): handle an edge case\n\nSee OPS-3 and PROJ-7.").unwrap();
+/// let keys = commit.issue_keys(&IssueKeyPattern::default());
+/// assert_eq!(keys, ["PROJ-12", "OPS-3", "PROJ-7"]);
+///
+/// let pattern = IssueKeyPattern {
+///     projects: vec!["PROJ".to_string()],
+///     ..IssueKeyPattern::default()
+/// };
+/// assert_eq!(commit.issue_keys(&pattern), ["PROJ-12", "PROJ-7"]);
+///
+/// // Words with non-ASCII letters are not keys.
+/// let commit = Commit::parse("fix: handle café-12 and ÉTÉ-3").unwrap();
+/// assert!(commit.issue_keys(&IssueKeyPattern::default()).is_empty());
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct IssueKeyPattern {
+    /// The project keys to look for, like `PROJ`. Any project key is
+    /// recognized if this is empty.
+    pub projects: Vec<String>,
+    /// Whether lowercase keys, like `proj-123`, are recognized as well.
+    pub case_insensitive: bool,
+}
+
+impl IssueKeyPattern {
+    /// Returns `true` if `project` is a project key that is looked for.
+    fn matches_project(&self, project: &str) -> bool {
+        let starts_with_letter = project
+            .chars()
+            .next()
+            .map_or(false, |c| c.is_ascii_alphabetic());
+        let valid = starts_with_letter
+            && project.len() >= 2
+            && project.chars().all(|c| {
+                c == '_'
+                    || c.is_ascii_digit()
+                    || c.is_ascii_uppercase()
+                    || (self.case_insensitive && c.is_ascii_lowercase())
+            });
+        valid
+            && (self.projects.is_empty()
+                || self.projects.iter().any(|key| {
+                    key == project || (self.case_insensitive && key.eq_ignore_ascii_case(project))
+                }))
+    }
+}
+
+/// Returns the distinct issue keys in the scope, description, body and
+/// footer values of a commit, in the order in which they first appear.
+pub(crate) fn issue_keys<'a>(commit: &Commit<'a>, pattern: &IssueKeyPattern) -> Vec<&'a str> {
+    let texts = commit
+        .scope
+        .into_iter()
+        .chain(Some(commit.desc))
+        .chain(commit.body)
+        .chain(commit.footer.iter().map(|footer| footer.value));
+
+    let mut keys = Vec::new();
+    for text in texts {
+        for key in scan(text, pattern) {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+    }
+    keys
+}
+
+/// Returns the issue keys in a text.
+fn scan<'a>(text: &'a str, pattern: &IssueKeyPattern) -> Vec<&'a str> {
+    let mut keys = Vec::new();
+    for (idx, _) in text.match_indices('-') {
+        let before = &text[..idx];
+        let project_start = before
+            .trim_end_matches(|c: char| c.is_ascii_alphanumeric() || c == '_')
+            .len();
+        let project = &before[project_start..];
+        if before[..project_start].ends_with(char::is_alphanumeric) {
+            continue;
+        }
+
+        let after = &text[idx + 1..];
+        let digits = after
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(after.len());
+        if digits == 0 || after[digits..].starts_with(|c: char| c.is_alphanumeric() || c == '_') {
+            continue;
+        }
+        if pattern.matches_project(project) {
+            keys.push(&text[project_start..idx + 1 + digits]);
+        }
+    }
+    keys
+}
@@ -1,9 +1,124 @@
 //! Defines structures that can be used to work with conventional commits.
 //! The implementation resembles the v1.0.0 specification defined over at [conventionalcommits.org](https://www.conventionalcommits.org/en/v1.0.0/#specification).
 
+#[cfg(feature = "serde")]
+pub mod as_message;
+mod autosquash;
+mod breaking;
+mod builder;
+#[cfg(feature = "serde")]
+mod changelog;
+mod cleanup;
+#[cfg(feature = "color")]
+mod color;
+mod commit_type;
+mod convention;
+mod cow;
+mod decode;
+#[cfg(feature = "miette")]
+mod diagnostic;
+mod error;
+mod extensions;
+mod footer_vec;
+mod format;
+mod grapheme;
+#[cfg(feature = "html")]
+mod html;
+mod invariant;
+mod issue_key;
+mod link;
+pub mod lint;
+mod log;
+mod markdown;
+mod mention;
+mod merge;
+mod message;
+mod order;
+mod owned;
+mod parser;
+mod person;
+mod reference;
+mod revert;
+mod scope;
+mod semantic;
+mod span;
+mod spec;
+mod squash;
+mod stable_hash;
+mod template;
+mod token;
+mod trailer;
+mod transform;
+mod validate;
+#[cfg(feature = "serde")]
+mod versioned;
+mod view;
+
+pub use autosquash::{strip_autosquash, Autosquash};
+pub use breaking::{BreakingChange, BreakingSource};
+pub use builder::{CommitBuilder, NoDesc, NoType, TypedCommitBuilder};
+#[cfg(feature = "serde")]
+pub use changelog::{ChangelogCommit, ChangelogNote, ChangelogReference, ChangelogRevert};
+pub use cleanup::{
+    normalize_line_endings, strip_comments, strip_comments_with, LineEnding, COMMENT_CHAR, SCISSORS,
+};
+#[cfg(feature = "color")]
+pub use color::Colored;
+pub use commit_type::CommitType;
+pub use convention::{Convention, ANGULAR_TYPES};
+pub use cow::{CommitCow, FooterCow};
+pub use decode::{decode_lossy, Encoding};
+pub use error::{ParseError, ParseErrorKind};
+pub use extensions::Extensions;
+pub use footer_vec::FooterVec;
+pub use format::{
+    wrap, BreakingStyle, FooterNormalization, FooterOrder, FormatOptions, DEFAULT_WRAP_WIDTH,
+};
+#[cfg(feature = "html")]
+pub use html::render_html;
+pub use invariant::InvariantViolation;
+pub use issue_key::IssueKeyPattern;
+pub use log::{CommitStream, Log, LOG_DELIMITER};
+pub use markdown::{Forge, LinkContext};
+pub use merge::{Merge, MergeKind};
+pub use message::ParsedMessage;
+pub use order::TYPE_ORDER;
+pub use owned::{CommitOwned, FooterOwned};
+pub use parser::ParseOptions;
+pub use person::Person;
+pub use reference::{IssueAction, IssueRef, Reference};
+pub use revert::Revert;
+pub use scope::Scope;
+pub use span::{CommitSpans, FooterSpans, Span};
+pub use spec::SpecVersion;
+pub use squash::split_squash;
+pub use token::{
+    FooterToken, ACKED_BY_TOKEN, CLOSES_TOKEN, CO_AUTHORED_BY_TOKEN, FIXES_TOKEN, REFS_TOKEN,
+    REVIEWED_BY_TOKEN, SIGNED_OFF_BY_TOKEN,
+};
+pub use trailer::FooterMode;
+pub use transform::Component;
+pub use validate::{Rules, Violation, ViolationKind};
+#[cfg(feature = "serde")]
+pub use versioned::{Versioned, SCHEMA_VERSION};
+pub use view::AsCommit;
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::{fmt, str::FromStr};
+use std::{
+    borrow::Cow,
+    convert::TryFrom,
+    fmt,
+    str::{self, FromStr},
+};
+
+/// Returns `true` if `value` is `false`, to skip serializing such flags.
+#[cfg(feature = "serde")]
+fn is_false(value: &bool) -> bool {
+    !*value
+}
 
 /// The `:<space>` separator.
 pub const SEPARATOR_COLON: &str = ": ";
@@ -14,6 +129,32 @@ pub const SEPARATOR_COLON: &str = ": ";
 /// value.
 pub const SEPARATOR_HASHTAG: &str = " #";
 
+/// The characters that separate multiple scopes, as in `feat(api,cli)` or
+/// `fix(core/parser)`.
+pub const SCOPE_SEPARATORS: &[char] = &[',', '/'];
+
+/// The marker in front of the colon of a header that marks a commit as a
+/// breaking change, as in `feat!: drop v1`.
+pub const BREAKING_CHANGE_MARKER: char = '!';
+
+/// The commit types that most tools know, as allowed by the conventional
+/// configuration of commitlint.
+///
+/// The specification itself only defines `feat` and `fix`. Lenient parsing
+/// returns these types in lowercase, see [`ParseOptions::strict`]. The types
+/// are listed in the order of the sections of a typical changelog, which is
+/// the [`TYPE_ORDER`] that commits are sorted in.
+pub const DEFAULT_TYPES: &[&str] = &[
+    "feat", "fix", "perf", "refactor", "revert", "docs", "style", "test", "build", "ci", "chore",
+];
+
+/// The footer token that marks a commit as a breaking change.
+pub const BREAKING_CHANGE_TOKEN: &str = "BREAKING CHANGE";
+
+/// The synonym of [`BREAKING_CHANGE_TOKEN`], as allowed by paragraph 16 of
+/// the specification.
+pub const BREAKING_CHANGE_TOKEN_ALIAS: &str = "BREAKING-CHANGE";
+
 /// A commit message.
 ///
 /// As per the specification, a commit message is made out of a mandatory
@@ -35,18 +176,120 @@ pub const SEPARATOR_HASHTAG: &str = " #";
 /// PR-close #124
 /// Signed-off-by: SirWindfield
 /// ```
+///
+/// More fields may be added in the future, so commits are created by
+/// parsing, with [`CommitBuilder`] or with [`Commit::from`], and read through
+/// their fields or accessors.
+///
+/// # Serde
+///
+/// With the `serde` feature, a commit is deserialized without copying: all of
+/// its components borrow from the input. Only strings with escape sequences
+/// cannot be borrowed and fail to deserialize.
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use conventional_commits_types::Commit;
+///
+/// let commit = Commit::parse("fix(api): reject empty ids\n\nRefs: #7").unwrap();
+/// let json = serde_json::to_string(&commit).unwrap();
+/// let deserialized: Commit<'_> = serde_json::from_str(&json).unwrap();
+/// assert_eq!(deserialized, commit);
+///
+/// let borrowed = |s: &str| {
+///     let start = json.as_ptr() as usize;
+///     (start..start + json.len()).contains(&(s.as_ptr() as usize))
+/// };
+/// assert!(borrowed(deserialized.scope.unwrap()));
+/// assert!(borrowed(deserialized.footer[0].value));
+/// # }
+/// ```
+///
+/// # JSON Schema
+///
+/// With the `schemars` feature, commits, footers and their separators
+/// implement `JsonSchema`, so services that expose commits over HTTP can
+/// publish the schema of their serde representation. The feature requires
+/// Rust 1.74.
+///
+/// ```
+/// # #[cfg(all(feature = "schemars", feature = "serde"))]
+/// # {
+/// use conventional_commits_types::{Commit, Footer, FooterSeparator, ParseOptions};
+/// use serde_json::Value;
+///
+/// let options = ParseOptions {
+///     autosquash: true,
+///     gitmoji: true,
+///     ..ParseOptions::default()
+/// };
+/// let message = "fixup! ✨ feat(api)!: add a schema\n\nA body.\n\nRefs #12\nReviewed-by: Jane";
+/// let mut commit = Commit::parse_with(message, &options).unwrap();
+/// commit.footer.push(Footer::from("Acked-by", FooterSeparator::Custom(" = "), "Bob"));
+/// commit.extensions.insert("pull-request", "42");
+///
+/// let schema = serde_json::to_value(schemars::schema_for!(Commit<'_>)).unwrap();
+/// let keys = |value: &Value| {
+///     let mut keys: Vec<_> = value.as_object().unwrap().keys().cloned().collect();
+///     keys.sort();
+///     keys
+/// };
+/// let json = serde_json::to_value(&commit).unwrap();
+/// assert_eq!(keys(&json), keys(&schema["properties"]));
+/// assert_eq!(keys(&json["footer"][0]), keys(&schema["$defs"]["Footer"]["properties"]));
+/// # }
+/// ```
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(
+    feature = "schemars",
+    derive(JsonSchema),
+    schemars(
+        description = "A commit message that follows the conventional commits specification."
+    )
+)]
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
 pub struct Commit<'a> {
+    /// The kind of the autosquash prefix of the header, like `fixup! `.
+    ///
+    /// Only set if the prefixes are stripped, see
+    /// [`ParseOptions::autosquash`].
+    pub autosquash: Option<Autosquash>,
     /// The optional body.
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub body: Option<&'a str>,
     /// The mandatory description.
     pub desc: &'a str,
+    /// Extra data attached by tools, which is not part of the message.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Extensions::is_empty")
+    )]
+    pub extensions: Extensions,
     /// A list of footers. Empty when none are part of the commit message.
-    pub footer: Vec<Footer<'a>>,
+    /// No footer or a single one are stored without allocating.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub footer: FooterVec<Footer<'a>>,
+    /// The gitmoji in front of the type, like `✨` or `:sparkles:`.
+    ///
+    /// Only set if gitmoji are recognized, see [`ParseOptions::gitmoji`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub gitmoji: Option<&'a str>,
+    /// Set if the header has the `!` breaking change marker.
+    ///
+    /// Unlike [`Commit::is_breaking_change`], this is not set by breaking
+    /// change footers, which tells a [`BreakingSource::Both`] apart from a
+    /// [`BreakingSource::Footer`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "crate::is_false")
+    )]
+    pub has_breaking_marker: bool,
     /// Set if the commit is a breaking change.
     pub is_breaking_change: bool,
     /// The optional scope.
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub scope: Option<&'a str>,
     /// The mandatory type.
     ///
@@ -56,94 +299,1325 @@ pub struct Commit<'a> {
 
 impl<'a> Commit<'a> {
     /// Creates a default commit.
-    pub fn new() -> Self {
-        Self::default()
+    pub const fn new() -> Self {
+        Self::from("", None, "", None, false, FooterVec::new())
     }
 
     /// Creates a commit with the given values.
-    pub fn from(
+    ///
+    /// Since this is a `const fn`, well-known commits without footers can be
+    /// defined as constants. It cannot look at the footers, so a breaking
+    /// change footer does not mark the commit as a breaking change. Use
+    /// [`Commit::with_footers`] or [`Commit::repair`] for that.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::{Commit, FooterVec};
+    ///
+    /// const INITIAL: Commit<'static> =
+    ///     Commit::from("chore", None, "initial commit", None, false, FooterVec::new());
+    /// assert_eq!(INITIAL.to_string(), "chore: initial commit");
+    /// ```
+    pub const fn from(
         ty: &'a str,
         scope: Option<&'a str>,
         desc: &'a str,
         body: Option<&'a str>,
         is_breaking_change: bool,
-        footer: Vec<Footer<'a>>,
+        footer: FooterVec<Footer<'a>>,
     ) -> Self {
         Self {
+            autosquash: None,
+            extensions: Extensions::new(),
+            gitmoji: None,
             ty,
             scope,
             desc,
             body,
+            has_breaking_marker: is_breaking_change,
             is_breaking_change,
             footer,
         }
     }
+
+    /// Creates a commit without a body and footers from a header.
+    pub const fn from_header(header: Header<'a>) -> Self {
+        Self {
+            autosquash: header.autosquash,
+            body: None,
+            desc: header.desc,
+            extensions: Extensions::new(),
+            footer: FooterVec::new(),
+            gitmoji: header.gitmoji,
+            has_breaking_marker: header.is_breaking_change,
+            is_breaking_change: header.is_breaking_change,
+            scope: header.scope,
+            ty: header.ty,
+        }
+    }
+
+    /// Returns the commit with the given type.
+    pub fn with_type(mut self, ty: &'a str) -> Self {
+        self.ty = ty;
+        self
+    }
+
+    /// Returns the commit with the given scope, or without one for `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::Commit;
+    ///
+    /// let commit = Commit::parse("feat(api): add an endpoint").unwrap();
+    /// assert_eq!(commit.clone().with_scope("server").to_string(), "feat(server): add an endpoint");
+    /// assert_eq!(commit.with_scope(None).to_string(), "feat: add an endpoint");
+    /// ```
+    pub fn with_scope(mut self, scope: impl Into<Option<&'a str>>) -> Self {
+        self.scope = scope.into();
+        self
+    }
+
+    /// Returns the commit with the given description.
+    pub fn with_desc(mut self, desc: &'a str) -> Self {
+        self.desc = desc;
+        self
+    }
+
+    /// Returns the commit with the given body, or without one for `None`.
+    pub fn with_body(mut self, body: impl Into<Option<&'a str>>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Returns the commit with the given footers.
+    ///
+    /// If any of them is a breaking change footer, the commit is marked as a
+    /// breaking change.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::{Commit, Footer, FooterSeparator};
+    ///
+    /// let commit = Commit::new().with_type("feat").with_desc("drop v1").with_footers(vec![
+    ///     Footer::from("BREAKING CHANGE", FooterSeparator::ColonSpace, "v1 is gone"),
+    /// ]);
+    /// assert!(commit.is_breaking_change());
+    /// ```
+    pub fn with_footers(mut self, footer: Vec<Footer<'a>>) -> Self {
+        self.is_breaking_change |= footer.iter().any(Footer::is_breaking_change);
+        self.footer = footer.into_iter().collect();
+        self
+    }
+
+    /// Adds a footer.
+    ///
+    /// Adding a breaking change footer marks the commit as a breaking change.
+    pub fn push_footer(&mut self, footer: Footer<'a>) -> &mut Self {
+        self.is_breaking_change |= footer.is_breaking_change();
+        self.footer.push(footer);
+        self
+    }
+
+    /// Sets whether the commit introduces a breaking change.
+    ///
+    /// Since breaking change footers mark a commit as a breaking change, they
+    /// are removed when it is set to `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::Commit;
+    ///
+    /// let mut commit = Commit::parse("feat: drop v1\n\nBREAKING CHANGE: v1 is gone\nRefs: #1").unwrap();
+    /// commit.set_breaking(false);
+    /// assert_eq!(commit.to_string(), "feat: drop v1\n\nRefs: #1");
+    /// ```
+    pub fn set_breaking(&mut self, is_breaking_change: bool) -> &mut Self {
+        self.is_breaking_change = is_breaking_change;
+        if !is_breaking_change {
+            self.has_breaking_marker = false;
+            self.footer.retain(|footer| !footer.is_breaking_change());
+        }
+        self
+    }
+
+    /// Checks that the commit is consistent, for example after its fields
+    /// were set directly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::{Commit, Footer, FooterSeparator, InvariantViolation};
+    ///
+    /// let footer = Footer::from("BREAKING CHANGE", FooterSeparator::ColonSpace, "v1 is gone");
+    /// let mut commit = Commit::from("feat", None, "drop v1", None, false, vec![footer].into());
+    /// assert_eq!(
+    ///     commit.check_invariants(),
+    ///     Err(InvariantViolation::UnmarkedBreakingFooter)
+    /// );
+    ///
+    /// commit.repair();
+    /// assert!(commit.is_breaking_change());
+    /// assert_eq!(commit.check_invariants(), Ok(()));
+    /// ```
+    pub fn check_invariants(&self) -> Result<(), InvariantViolation> {
+        if !self.is_breaking_change && self.footer.iter().any(Footer::is_breaking_change) {
+            return Err(InvariantViolation::UnmarkedBreakingFooter);
+        }
+        Ok(())
+    }
+
+    /// Makes the commit consistent, so that [`Commit::check_invariants`]
+    /// succeeds.
+    ///
+    /// A commit with a breaking change footer is marked as a breaking
+    /// change. The footers are kept either way.
+    pub fn repair(&mut self) -> &mut Self {
+        self.is_breaking_change |= self.footer.iter().any(Footer::is_breaking_change);
+        self
+    }
+
+    /// Returns the type.
+    pub fn ty(&self) -> &'a str {
+        self.ty
+    }
+
+    /// Returns the type as a [`CommitType`], for matching on well-known
+    /// types.
+    pub fn commit_type(&self) -> CommitType<'a> {
+        CommitType::from(self.ty)
+    }
+
+    /// Returns the scope.
+    pub fn scope(&self) -> Option<&'a str> {
+        self.scope
+    }
+
+    /// Returns the description.
+    pub fn desc(&self) -> &'a str {
+        self.desc
+    }
+
+    /// Returns the body.
+    pub fn body(&self) -> Option<&'a str> {
+        self.body
+    }
+
+    /// Returns all footers, in the order of the message.
+    pub fn all_footers(&self) -> &[Footer<'a>] {
+        &self.footer
+    }
+
+    /// Returns `true` if the commit introduces a breaking change.
+    pub fn is_breaking_change(&self) -> bool {
+        self.is_breaking_change
+    }
+
+    /// Returns the kind of the autosquash prefix of the header.
+    pub fn autosquash(&self) -> Option<Autosquash> {
+        self.autosquash
+    }
+
+    /// Returns the gitmoji in front of the type.
+    pub fn gitmoji(&self) -> Option<&'a str> {
+        self.gitmoji
+    }
+
+    /// Returns `true` if the commit reverts an earlier one, as its type is
+    /// `revert`.
+    ///
+    /// See [`Revert`] for the reverted commits.
+    pub fn is_revert(&self) -> bool {
+        self.ty.eq_ignore_ascii_case("revert")
+    }
+
+    /// Returns `true` if the commit is meant to be squashed into an earlier
+    /// one, as its header had a `fixup! `, `squash! ` or `amend! ` prefix.
+    ///
+    /// The prefixes are only recorded if they are stripped while parsing,
+    /// see [`ParseOptions::autosquash`]. Use [`ParsedMessage::is_fixup`] for
+    /// messages parsed without doing so.
+    pub fn is_fixup(&self) -> bool {
+        self.autosquash.is_some()
+    }
+
+    /// Returns `true` if the commit is marked as work in progress, by the
+    /// type `wip` or a description that starts with `WIP` or `[WIP]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::Commit;
+    ///
+    /// assert!(Commit::parse("feat: WIP add a parser").unwrap().is_wip());
+    /// assert!(Commit::parse("wip: add a parser").unwrap().is_wip());
+    /// assert!(!Commit::parse("feat: wipe the cache").unwrap().is_wip());
+    /// ```
+    pub fn is_wip(&self) -> bool {
+        self.ty.eq_ignore_ascii_case("wip") || message::is_wip_subject(self.desc)
+    }
+
+    /// Returns a builder that checks the components of the commit.
+    ///
+    /// See [`CommitBuilder`] for an example.
+    pub fn builder() -> CommitBuilder<'a> {
+        CommitBuilder::new()
+    }
+
+    /// Parses a commit message.
+    ///
+    /// The returned commit borrows all of its components from `input`. A
+    /// `BREAKING CHANGE` or `BREAKING-CHANGE` footer or a `!` in front of the
+    /// header's colon mark the commit as a breaking change. The value of a
+    /// breaking change footer extends over all following paragraphs until
+    /// the next footer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::Commit;
+    ///
+    /// let commit = Commit::parse("feat(parser)!: add a parser\n\nFixes #1").unwrap();
+    /// assert_eq!(commit.ty, "feat");
+    /// assert_eq!(commit.scope, Some("parser"));
+    /// assert!(commit.is_breaking_change);
+    /// assert_eq!(commit.footer[0].value, "1");
+    ///
+    /// let commit = Commit::parse("feat: x\n\nBREAKING CHANGE: a\n\nmore paragraph").unwrap();
+    /// assert!(commit.is_breaking_change);
+    /// assert_eq!(commit.body, None);
+    /// assert_eq!(commit.footer[0].value, "a\n\nmore paragraph");
+    /// ```
+    pub fn parse(input: &'a str) -> Result<Self, ParseError> {
+        Self::parse_with(input, &ParseOptions::default())
+    }
+
+    /// Parses a commit message using the given options.
+    ///
+    /// See [`ParseOptions`] for the available settings.
+    pub fn parse_with(input: &'a str, options: &ParseOptions) -> Result<Self, ParseError> {
+        Self::parse_with_spans(input, options).map(|(commit, _)| commit)
+    }
+
+    /// Parses a commit message and returns the byte ranges of its components
+    /// inside of `input` alongside of it.
+    ///
+    /// This is useful for editors and linters that want to point at the exact
+    /// location of a component.
+    pub fn parse_with_spans(
+        input: &'a str,
+        options: &ParseOptions,
+    ) -> Result<(Self, CommitSpans), ParseError> {
+        let mut errors = Vec::new();
+        let parsed = parser::parse(input, options, &mut errors);
+        parser::first_error(parsed, errors)
+    }
+
+    /// Parses a commit message that is not guaranteed to be valid UTF-8, such
+    /// as the output of `git cat-file`.
+    ///
+    /// Valid UTF-8 is parsed without copying. Other messages are decoded into
+    /// `buf` using [`ParseOptions::encoding`] and the commit borrows from
+    /// there instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::{Commit, ParseOptions};
+    ///
+    /// let mut buf = String::new();
+    /// let commit = Commit::parse_bytes(b"feat: add caf\xe9", &mut buf, &ParseOptions::default()).unwrap();
+    /// assert_eq!(commit.desc, "add café");
+    /// ```
+    pub fn parse_bytes(
+        input: &'a [u8],
+        buf: &'a mut String,
+        options: &ParseOptions,
+    ) -> Result<Self, ParseError> {
+        let input = match str::from_utf8(input) {
+            Ok(input) => input,
+            Err(_) => {
+                decode::decode_into(input, options.encoding, buf);
+                let buf: &'a String = buf;
+                buf
+            }
+        };
+        Self::parse_with(input, options)
+    }
+
+    /// Validates a commit message against the rules, reporting every
+    /// violation in the order in which they appear in the message.
+    ///
+    /// An empty list means that the message is valid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::{Commit, Rules, ViolationKind};
+    ///
+    /// let rules = Rules {
+    ///     types: Some(vec!["feat".to_string(), "fix".to_string()]),
+    ///     forbid_trailing_period: true,
+    ///     ..Rules::default()
+    /// };
+    /// let violations = Commit::validate("docs: update the readme.", &rules);
+    /// let kinds: Vec<_> = violations.iter().map(|violation| violation.kind()).collect();
+    /// assert_eq!(kinds, [ViolationKind::TypeNotAllowed, ViolationKind::TrailingPeriod]);
+    /// ```
+    pub fn validate(input: &str, rules: &Rules) -> Vec<Violation> {
+        validate::validate(input, rules)
+    }
+
+    /// Parses a commit message without stopping at the first problem.
+    ///
+    /// Instead of failing, a best-effort commit is returned together with a
+    /// diagnostic for every part of the message that could not be understood.
+    /// For a valid message, the list of diagnostics is empty. The options
+    /// decide what counts as a problem, so lenient parsing reports fewer
+    /// diagnostics than strict parsing.
+    pub fn parse_lossy(input: &'a str, options: &ParseOptions) -> (Self, Vec<ParseError>) {
+        let mut errors = Vec::new();
+        let (commit, _) = parser::parse(input, options, &mut errors);
+        (commit, errors)
+    }
+
+    /// Returns the individual scopes of the commit.
+    ///
+    /// See [`Header::scopes`] for details.
+    pub fn scopes(&self) -> impl Iterator<Item = &'a str> {
+        parser::split_scopes(self.scope)
+    }
+
+    /// Returns the first footer with the given token.
+    ///
+    /// Tools disagree on the casing of some tokens, e.g. GitHub writes
+    /// `Co-authored-by` while others write `Co-Authored-By`. Use
+    /// [`CaseSensitivity::Insensitive`] to find either one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::{CaseSensitivity, Commit};
+    ///
+    /// let commit = Commit::parse("feat: pair up\n\nCo-Authored-By: Jane <jane@example.com>").unwrap();
+    /// let footer = commit.find_footer("co-authored-by", CaseSensitivity::Insensitive).unwrap();
+    /// assert_eq!(footer.token, "Co-Authored-By");
+    /// ```
+    pub fn find_footer(&self, token: &str, case: CaseSensitivity) -> Option<&Footer<'a>> {
+        self.footer
+            .iter()
+            .find(|footer| footer.matches_token(token, case))
+    }
+
+    /// Returns the first footer with the given token, as the specification
+    /// matches them.
+    ///
+    /// See [`Footer::has_token`] for how tokens are compared.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::Commit;
+    ///
+    /// let commit = Commit::parse("feat: drop v1\n\nreviewed-by: Jane\nBREAKING-CHANGE: v1 is gone").unwrap();
+    /// assert_eq!(commit.footer("Reviewed-by").unwrap().value, "Jane");
+    /// assert_eq!(commit.footer("BREAKING CHANGE").unwrap().value, "v1 is gone");
+    /// assert!(commit.footer("Refs").is_none());
+    /// ```
+    pub fn footer(&self, token: &str) -> Option<&Footer<'a>> {
+        self.footer.iter().find(|footer| footer.has_token(token))
+    }
+
+    /// Returns the explanation of the breaking change, or `None` if the
+    /// commit is not breaking.
+    ///
+    /// This is the value of the first `BREAKING CHANGE` or `BREAKING-CHANGE`
+    /// footer. If the breaking change is only marked with `!`, the
+    /// description is used instead, as the specification allows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::Commit;
+    ///
+    /// let commit = Commit::parse("feat!: drop v1\n\nBREAKING CHANGE: use v2 instead").unwrap();
+    /// assert_eq!(commit.breaking_change_description(), Some("use v2 instead"));
+    ///
+    /// let commit = Commit::parse("feat!: drop v1").unwrap();
+    /// assert_eq!(commit.breaking_change_description(), Some("drop v1"));
+    ///
+    /// let commit = Commit::parse("feat: add v2").unwrap();
+    /// assert_eq!(commit.breaking_change_description(), None);
+    /// ```
+    pub fn breaking_change_description(&self) -> Option<&'a str> {
+        self.breaking_change()
+            .map(|breaking_change| breaking_change.description)
+    }
+
+    /// Returns the breaking change of the commit, or `None` if it is not
+    /// breaking.
+    ///
+    /// Whether the header had the `!` marker is taken from
+    /// [`Commit::has_breaking_marker`]. See [`BreakingChange`] for an
+    /// example.
+    pub fn breaking_change(&self) -> Option<BreakingChange<'a>> {
+        breaking::breaking_change(self, self.has_breaking_marker)
+    }
+
+    /// Returns the breaking change of the commit, or `None` if it is not
+    /// breaking, taking the `!` marker from the spans of its message.
+    ///
+    /// The spans have to be the ones returned by
+    /// [`Commit::parse_with_spans`] along with the commit.
+    pub fn breaking_change_with_spans(&self, spans: &CommitSpans) -> Option<BreakingChange<'a>> {
+        breaking::breaking_change(self, spans.breaking_marker.is_some())
+    }
+
+    /// Returns the footers with the given token, in the order of the
+    /// message, as the specification matches them.
+    ///
+    /// See [`Footer::has_token`] for how tokens are compared.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::Commit;
+    ///
+    /// let commit = Commit::parse("fix: close issues\n\nRefs: #1\nrefs: #2").unwrap();
+    /// let values: Vec<_> = commit.footers("Refs").map(|footer| footer.value).collect();
+    /// assert_eq!(values, ["#1", "#2"]);
+    /// ```
+    pub fn footers<'s>(&'s self, token: &'s str) -> impl Iterator<Item = &'s Footer<'a>> {
+        self.footer
+            .iter()
+            .filter(move |footer| footer.has_token(token))
+    }
+
+    /// Returns the footers with the given token, in the order of the
+    /// message, compared as given by `case`.
+    ///
+    /// Unlike [`Commit::footers`], `BREAKING CHANGE` and `BREAKING-CHANGE`
+    /// are different tokens here.
+    pub fn footers_with<'s>(
+        &'s self,
+        token: &'s str,
+        case: CaseSensitivity,
+    ) -> impl Iterator<Item = &'s Footer<'a>> {
+        self.footer
+            .iter()
+            .filter(move |footer| footer.matches_token(token, case))
+    }
+
+    /// Formats the commit as a message, expressing a breaking change as
+    /// given by `style`.
+    ///
+    /// When a `BREAKING CHANGE` footer is requested but the commit does not
+    /// have one, a footer with the description as its value is added.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::{BreakingStyle, Commit};
+    ///
+    /// let commit = Commit::parse("feat(api)!: drop v1").unwrap();
+    /// assert_eq!(commit.to_message(BreakingStyle::Bang), "feat(api)!: drop v1");
+    /// assert_eq!(
+    ///     commit.to_message(BreakingStyle::Footer),
+    ///     "feat(api): drop v1\n\nBREAKING CHANGE: drop v1"
+    /// );
+    /// ```
+    pub fn to_message(&self, style: BreakingStyle) -> String {
+        self.format(&FormatOptions {
+            breaking_style: style,
+            ..FormatOptions::default()
+        })
+    }
+
+    /// Formats the commit as a message using the given options.
+    ///
+    /// See [`FormatOptions`] for the available settings.
+    pub fn format(&self, options: &FormatOptions) -> String {
+        let mut message = String::new();
+        self.write_to(&mut message, options)
+            .expect("writing to a String cannot fail");
+        message
+    }
+
+    /// Writes the commit as a message to `w` using the given options.
+    ///
+    /// Unlike [`Commit::format`], this does not allocate a `String` for the
+    /// message, which helps when writing many commits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::{Commit, FormatOptions};
+    ///
+    /// let mut out = String::new();
+    /// for message in &["feat: a", "fix: b"] {
+    ///     let commit = Commit::parse(message).unwrap();
+    ///     commit.write_to(&mut out, &FormatOptions::default()).unwrap();
+    ///     out.push('\n');
+    /// }
+    /// assert_eq!(out, "feat: a\nfix: b\n");
+    /// ```
+    pub fn write_to<W: fmt::Write>(&self, w: &mut W, options: &FormatOptions) -> fmt::Result {
+        format::write_commit(w, self, options)
+    }
+
+    /// Returns the first line of the message, `type(scope)!: description`,
+    /// without the body and the footers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::Commit;
+    ///
+    /// let commit = Commit::parse("fix(api)!: reject empty ids\n\nIds were optional.").unwrap();
+    /// assert_eq!(commit.summary(), "fix(api)!: reject empty ids");
+    /// ```
+    pub fn summary(&self) -> String {
+        self.header().to_string()
+    }
+
+    /// Returns the description, truncated to at most `max` visible
+    /// characters.
+    ///
+    /// If the description is longer, its end is replaced with `…`, which
+    /// counts as one of the `max` characters. Characters are counted as
+    /// grapheme clusters, so combined emoji, flags and letters with combining
+    /// accents are never split. Without the `unicode-segmentation` feature,
+    /// the clusters are an approximation that may split rare ones.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::Commit;
+    ///
+    /// let commit = Commit::parse("feat: 👩‍💻 support remote work").unwrap();
+    /// assert_eq!(commit.desc_truncated(10), "👩‍💻 support…");
+    /// assert_eq!(commit.desc_truncated(50), "👩‍💻 support remote work");
+    /// ```
+    pub fn desc_truncated(&self, max: usize) -> Cow<'a, str> {
+        grapheme::truncate(self.desc, max)
+    }
+
+    /// Returns the commit as a message that is displayed with ANSI colors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::Commit;
+    ///
+    /// let commit = Commit::parse("fix(ui)!: drop the old theme").unwrap();
+    /// assert_eq!(
+    ///     commit.colored().to_string(),
+    ///     "\x1b[1;34mfix\x1b[0m(\x1b[33mui\x1b[0m)\x1b[1;31m!\x1b[0m: drop the old theme"
+    /// );
+    /// ```
+    #[cfg(feature = "color")]
+    pub fn colored(&self) -> Colored<'_, 'a> {
+        Colored::new(self)
+    }
+
+    /// Renders the commit as an HTML `<article>`.
+    ///
+    /// See [`render_html`] for rendering a list of commits.
+    #[cfg(feature = "html")]
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        html::write_commit(&mut html, self).expect("writing to a String cannot fail");
+        html
+    }
+
+    /// Renders the commit as Markdown.
+    ///
+    /// References like `#123` and `owner/repo#123`, commit hashes and
+    /// mentions are turned into links to the repository of `context`. The
+    /// type and scope are written in bold and the footers as a list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::{Commit, Forge, LinkContext};
+    ///
+    /// let context = LinkContext::new("https://github.com/owner/repo", Forge::GitHub);
+    /// let commit = Commit::parse("fix(api): handle #12\n\nReviewed-by: @jane").unwrap();
+    /// assert_eq!(
+    ///     commit.to_markdown(&context),
+    ///     "**fix(api):** handle [#12](https://github.com/owner/repo/issues/12)\n\n\
+    ///      - **Reviewed-by:** [@jane](https://github.com/jane)"
+    /// );
+    /// ```
+    pub fn to_markdown(&self, context: &LinkContext<'_>) -> String {
+        let mut markdown = String::new();
+        markdown::write_commit(&mut markdown, self, context)
+            .expect("writing to a String cannot fail");
+        markdown
+    }
+
+    /// Returns a hash of the commit that stays the same across platforms,
+    /// compilers and releases of this crate.
+    ///
+    /// Unlike the [`Hash`](std::hash::Hash) implementation, whose values may
+    /// change with the standard library, this hash can key caches that
+    /// outlive a process, like those of incremental changelog tools. It is
+    /// the 64-bit FNV-1a hash of the components, so it is not suited for
+    /// untrusted input that may try to provoke collisions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::Commit;
+    ///
+    /// let commit = Commit::parse("feat(api): add an endpoint\n\nRefs: #12").unwrap();
+    /// assert_eq!(commit.stable_hash(), 0x70f0_bf01_8539_f5a1);
+    /// ```
+    pub fn stable_hash(&self) -> u64 {
+        stable_hash::stable_hash(self)
+    }
+
+    /// Returns the canonical message of the commit.
+    ///
+    /// The type is lowercased and superfluous whitespace is removed from all
+    /// components. A breaking change is marked with `!`, `BREAKING-CHANGE`
+    /// footers become `BREAKING CHANGE` footers, spaces in other footer
+    /// tokens are replaced with `-` and custom footer separators with `: `.
+    /// Lines are ended with `\n`.
+    ///
+    /// Parsing the canonical message yields a commit whose canonical message
+    /// is the same. Autosquash prefixes and gitmojis are kept, so the
+    /// options used for parsing have to strip them if the commit has any.
+    /// Use [`Commit::normalize_with`] for commits parsed with another
+    /// convention or revision of the specification.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::{Commit, ParseOptions};
+    ///
+    /// let message = "Feat( api ):  add  endpoint\n\nBREAKING-CHANGE: v2 ";
+    /// let commit = Commit::parse_with(message, &ParseOptions::lenient()).unwrap();
+    /// let canonical = commit.normalize();
+    /// assert_eq!(canonical, "feat(api)!: add endpoint\n\nBREAKING CHANGE: v2");
+    /// assert_eq!(Commit::parse(&canonical).unwrap().normalize(), canonical);
+    /// ```
+    pub fn normalize(&self) -> String {
+        self.normalize_with(&ParseOptions::default())
+    }
+
+    /// Returns the canonical message of a commit parsed with `options`.
+    ///
+    /// Works like [`Commit::normalize`], but breaking changes are expressed
+    /// as the convention and revision of `options` require: with a
+    /// `BREAKING CHANGE` footer where the `!` marker is not allowed, and
+    /// `BREAKING-CHANGE` footers are only renamed where they mark breaking
+    /// changes. Parsing the canonical message strictly with the same
+    /// convention and revision succeeds for any commit that `options`
+    /// accepted, as long as its type is one that strict parsing allows:
+    /// types are only lowercased, so the Angular convention still rejects a
+    /// `chore` commit that lenient parsing accepted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::{Commit, Convention, ParseOptions};
+    ///
+    /// let options = ParseOptions {
+    ///     convention: Convention::Angular,
+    ///     ..ParseOptions::lenient()
+    /// };
+    /// let commit = Commit::parse_with("feat(api):  drop v1\n\nBREAKING CHANGE: v2", &options).unwrap();
+    /// let canonical = commit.normalize_with(&options);
+    /// assert_eq!(canonical, "feat(api): drop v1\n\nBREAKING CHANGE: v2");
+    ///
+    /// let strict = ParseOptions {
+    ///     convention: Convention::Angular,
+    ///     ..ParseOptions::default()
+    /// };
+    /// let reparsed = Commit::parse_with(&canonical, &strict).unwrap();
+    /// assert!(reparsed.is_breaking_change);
+    /// assert_eq!(reparsed.normalize_with(&strict), canonical);
+    /// ```
+    pub fn normalize_with(&self, options: &ParseOptions) -> String {
+        format::normalize(self, options)
+    }
+
+    /// Sorts the footers into a stable order and removes duplicates, as
+    /// configured by `options`.
+    ///
+    /// See [`FooterNormalization`] for an example.
+    pub fn normalize_footers(&mut self, options: &FooterNormalization) {
+        format::normalize_footers(&mut self.footer, options);
+    }
+
+    /// Returns a commit whose components are rewritten by `f`.
+    ///
+    /// The closure is called with the type, scope, description, body and the
+    /// token and value of each footer, and returns their new text. Scopes
+    /// and bodies that become empty are removed, and a footer that becomes a
+    /// `BREAKING CHANGE` footer marks the commit as a breaking change.
+    /// Unchanged components stay borrowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::{Commit, Component};
+    /// use std::borrow::Cow;
+    ///
+    /// let commit = Commit::parse("fix: handle #12\n\nRefs: #12\nReviewed-by: Jane <jane@example.com>").unwrap();
+    /// let migrated = commit.map(|component, text| match component {
+    ///     Component::Desc | Component::FooterValue if text.contains("#12") => {
+    ///         Cow::Owned(text.replace("#12", "#1012"))
+    ///     }
+    ///     _ => Cow::Borrowed(text),
+    /// });
+    /// assert_eq!(
+    ///     migrated.to_string(),
+    ///     "fix: handle #1012\n\nRefs: #1012\nReviewed-by: Jane <jane@example.com>"
+    /// );
+    /// ```
+    pub fn map<F>(&self, f: F) -> CommitCow<'a>
+    where
+        F: FnMut(Component, &'a str) -> Cow<'a, str>,
+    {
+        transform::map(self, f)
+    }
+
+    /// Returns `true` if the two commits mean the same, even if their
+    /// messages are formatted differently.
+    ///
+    /// The types are compared case-insensitively, and the description, body
+    /// and footer values by their words only. The order of the footers and
+    /// the case of their tokens do not matter. Whether the breaking change is
+    /// marked with `!` or a `BREAKING CHANGE` footer does not matter either,
+    /// so the breaking change footers are not compared. This helps to find
+    /// commits that were cherry-picked to another branch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::{Commit, ParseOptions};
+    ///
+    /// let commit = Commit::parse("feat!: add an endpoint\n\nRefs: #1\nAcked-by: Jane").unwrap();
+    /// let picked = Commit::parse_with(
+    ///     "Feat: add an endpoint\n\nacked-by: Jane\nRefs: #1\nBREAKING CHANGE: v2",
+    ///     &ParseOptions::lenient(),
+    /// )
+    /// .unwrap();
+    /// assert!(commit.eq_semantic(&picked));
+    /// assert_ne!(commit, picked);
+    /// ```
+    pub fn eq_semantic(&self, other: &Commit<'_>) -> bool {
+        semantic::eq_semantic(self, other)
+    }
+
+    /// Returns the references to issues and pull requests in the
+    /// description, body and footers.
+    ///
+    /// Recognized are `#123`, `owner/repo#123` and the URLs of issues and
+    /// pull requests on GitHub and GitLab. A closing keyword like `Fixes`
+    /// in front of a reference, or as the token of its footer, is returned
+    /// as its action. See [`Reference`] for an example.
+    pub fn references(&self) -> Vec<Reference<'a>> {
+        reference::references(self)
+    }
+
+    /// Returns the distinct issues and pull requests the commit refers to in
+    /// its description, body and footers, with what it does to them.
+    ///
+    /// Unlike [`Commit::references`], every issue is only returned once. See
+    /// [`IssueRef`] for an example.
+    pub fn issue_refs(&self) -> Vec<IssueRef<'a>> {
+        reference::issue_refs(self)
+    }
+
+    /// Returns the distinct issue keys, like `PROJ-123`, in the scope,
+    /// description, body and footer values.
+    ///
+    /// See [`IssueKeyPattern`] for which keys are recognized.
+    pub fn issue_keys(&self, pattern: &IssueKeyPattern) -> Vec<&'a str> {
+        issue_key::issue_keys(self, pattern)
+    }
+
+    /// Returns the distinct users mentioned in the body and footer values,
+    /// like `jane` for `@jane`.
+    ///
+    /// Team mentions like `@org/team` are returned as `org/team`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::Commit;
+    ///
+    /// let commit = Commit::parse("feat: add a parser\n\nThanks @octocat!\n\nReviewed-by: @org/core").unwrap();
+    /// assert_eq!(commit.mentions(), ["octocat", "org/core"]);
+    /// ```
+    pub fn mentions(&self) -> Vec<&'a str> {
+        mention::mentions(self)
+    }
+
+    /// Returns the people named in the footers with the given token, like
+    /// the co-authors of `Co-authored-by` footers.
+    ///
+    /// Tokens are compared case-insensitively. Footers whose value is not of
+    /// the form `Name <email>` are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::Commit;
+    ///
+    /// let commit = Commit::parse("feat: pair up\n\nCo-Authored-By: Jane <jane@example.com>").unwrap();
+    /// let authors = commit.people("Co-authored-by");
+    /// assert_eq!(authors[0].name, "Jane");
+    /// ```
+    pub fn people(&self, token: &str) -> Vec<Person<'a>> {
+        self.footer
+            .iter()
+            .filter(|footer| footer.matches_token(token, CaseSensitivity::Insensitive))
+            .filter_map(Footer::person)
+            .collect()
+    }
+
+    /// Returns the co-authors named in the `Co-authored-by` footers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::Commit;
+    ///
+    /// let message = "feat: pair up\n\nCo-authored-by: Jane <jane@example.com>\nSigned-off-by: Joe <joe@example.com>";
+    /// let commit = Commit::parse(message).unwrap();
+    /// assert_eq!(commit.co_authors()[0].name, "Jane");
+    /// assert_eq!(commit.signers()[0].email, "joe@example.com");
+    /// ```
+    pub fn co_authors(&self) -> Vec<Person<'a>> {
+        self.people(CO_AUTHORED_BY_TOKEN)
+    }
+
+    /// Returns the people who signed off the commit in the `Signed-off-by`
+    /// footers.
+    pub fn signers(&self) -> Vec<Person<'a>> {
+        self.people(SIGNED_OFF_BY_TOKEN)
+    }
+
+    /// Returns the header of the commit.
+    ///
+    /// The breaking change flag of the header mirrors the one of the commit,
+    /// which is also set by `BREAKING CHANGE` footers.
+    pub fn header(&self) -> Header<'a> {
+        Header {
+            autosquash: self.autosquash,
+            desc: self.desc,
+            gitmoji: self.gitmoji,
+            is_breaking_change: self.is_breaking_change,
+            scope: self.scope,
+            ty: self.ty,
+        }
+    }
 }
 
-/// A commit message footer.
+impl<'a> TryFrom<&'a str> for Commit<'a> {
+    type Error = ParseError;
+
+    /// Parses a commit message, as [`Commit::parse`] does.
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        Self::parse(input)
+    }
+}
+
+impl fmt::Display for Commit<'_> {
+    /// Formats the commit as a message, `type(scope)!: desc`, followed by
+    /// the body and the footers, each separated by an empty line.
+    ///
+    /// A breaking change is expressed by the `!` marker, unless the commit
+    /// already has a `BREAKING CHANGE` footer. Use [`Commit::to_message`] to
+    /// choose the style.
+    ///
+    /// Lines are ended with `\n`, also where the body or footers contain
+    /// `\r\n` line endings. Use [`Commit::format`] with
+    /// [`FormatOptions::line_ending`] to write other line endings.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let style = if self.footer.iter().any(Footer::is_breaking_change) {
+            BreakingStyle::Footer
+        } else {
+            BreakingStyle::Bang
+        };
+        let options = FormatOptions {
+            breaking_style: style,
+            ..FormatOptions::default()
+        };
+        format::write_commit(f, self, &options)
+    }
+}
+
+/// The header of a commit message, its first line.
+///
+/// # Example
+///
+/// ```text
+/// feat(some scope)!: a short and concise description
+/// ```
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Header<'a> {
+    /// The kind of the autosquash prefix, like `fixup! `.
+    ///
+    /// Only set if the prefixes are stripped, see
+    /// [`ParseOptions::autosquash`].
+    pub autosquash: Option<Autosquash>,
+    /// The mandatory description.
+    pub desc: &'a str,
+    /// The gitmoji in front of the type, like `✨` or `:sparkles:`.
+    ///
+    /// Only set if gitmoji are recognized, see [`ParseOptions::gitmoji`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub gitmoji: Option<&'a str>,
+    /// Set if the header contains the `!` breaking change marker.
+    pub is_breaking_change: bool,
+    /// The optional scope.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub scope: Option<&'a str>,
+    /// The mandatory type.
+    pub ty: &'a str,
+}
+
+impl<'a> Header<'a> {
+    /// Creates a default header.
+    pub const fn new() -> Self {
+        Self::from("", None, "", false)
+    }
+
+    /// Creates a header with the given values.
+    pub const fn from(
+        ty: &'a str,
+        scope: Option<&'a str>,
+        desc: &'a str,
+        is_breaking_change: bool,
+    ) -> Self {
+        Self {
+            autosquash: None,
+            gitmoji: None,
+            ty,
+            scope,
+            desc,
+            is_breaking_change,
+        }
+    }
+
+    /// Returns the individual scopes of the header.
+    ///
+    /// The scope is split at the [`SCOPE_SEPARATORS`] and whitespace around
+    /// each part is removed. A header without a scope has no scopes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::Header;
+    ///
+    /// let header = Header::parse("feat(api, cli/args): add flags").unwrap();
+    /// assert_eq!(header.scopes().collect::<Vec<_>>(), ["api", "cli", "args"]);
+    /// ```
+    pub fn scopes(&self) -> impl Iterator<Item = &'a str> {
+        parser::split_scopes(self.scope)
+    }
+
+    /// Parses the header of a commit message.
+    ///
+    /// Only the first line of `input` is looked at, the body and footers are
+    /// neither parsed nor validated. This makes it a cheap way to classify
+    /// commits when only the type, scope or breaking change marker is needed.
+    pub fn parse(input: &'a str) -> Result<Self, ParseError> {
+        Self::parse_with(input, &ParseOptions::default())
+    }
+
+    /// Parses the header of a commit message using the given options.
+    pub fn parse_with(input: &'a str, options: &ParseOptions) -> Result<Self, ParseError> {
+        let mut errors = Vec::new();
+        let (header, _) =
+            parser::parse_header(parser::split_first_line(input).0, options, &mut errors);
+        parser::first_error(header, errors)
+    }
+}
+
+impl fmt::Display for Header<'_> {
+    /// Formats the header as `type(scope)!: description`, with the `!` only
+    /// for breaking changes.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format::write_header(f, self, self.is_breaking_change)
+    }
+}
+
+/// A commit message footer.
+///
+/// More fields may be added in the future, so footers are created with
+/// [`Footer::from`] and read through their fields or accessors.
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
 pub struct Footer<'a> {
     /// The footer word token.
     pub token: &'a str,
     /// The separator.
-    pub separator: FooterSeparator,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub separator: FooterSeparator<'a>,
     /// The footer's value.
+    ///
+    /// The value may span multiple lines, in which case it contains the line
+    /// breaks of the original message.
     pub value: &'a str,
 }
 
 impl<'a> Footer<'a> {
     /// Creates a default footer.
-    pub fn new() -> Self {
-        Self::default()
+    pub const fn new() -> Self {
+        Self::from("", FooterSeparator::ColonSpace, "")
     }
 
     /// Creates a footer with the given values.
-    pub fn from(token: &'a str, separator: FooterSeparator, value: &'a str) -> Self {
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::{Footer, FooterSeparator};
+    ///
+    /// const SIGN_OFF: Footer<'static> =
+    ///     Footer::from("Signed-off-by", FooterSeparator::ColonSpace, "Jane <jane@example.com>");
+    /// assert_eq!(SIGN_OFF.to_string(), "Signed-off-by: Jane <jane@example.com>");
+    /// ```
+    pub const fn from(token: &'a str, separator: FooterSeparator<'a>, value: &'a str) -> Self {
         Self {
             token,
             separator,
             value,
         }
     }
+
+    /// Returns the token.
+    pub fn token(&self) -> &'a str {
+        self.token
+    }
+
+    /// Returns the separator between the token and the value.
+    pub fn separator(&self) -> FooterSeparator<'a> {
+        self.separator
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> &'a str {
+        self.value
+    }
+
+    /// Returns `true` if the token is valid as per the specification.
+    ///
+    /// A valid token is a single word that uses `-` in place of whitespace,
+    /// like `Signed-off-by`, with `BREAKING CHANGE` being the only exception.
+    pub fn is_valid_token(&self) -> bool {
+        parser::is_valid_token(self.token)
+    }
+
+    /// Returns the corrected spelling of an invalid token, e.g.
+    /// `Signed-off-by` for `Signed off by`.
+    ///
+    /// Returns `None` if the token is already valid.
+    pub fn suggested_token(&self) -> Option<String> {
+        if self.is_valid_token() {
+            return None;
+        }
+        let words: Vec<_> = self.token.split_whitespace().collect();
+        if words.is_empty() {
+            None
+        } else {
+            Some(words.join("-"))
+        }
+    }
+
+    /// Returns `true` if the footer has the given token.
+    ///
+    /// The original casing of the token is kept either way. Case-insensitive
+    /// comparisons only ignore the case of ASCII letters.
+    pub fn matches_token(&self, token: &str, case: CaseSensitivity) -> bool {
+        match case {
+            CaseSensitivity::Sensitive => self.token == token,
+            CaseSensitivity::Insensitive => self.token.eq_ignore_ascii_case(token),
+        }
+    }
+
+    /// Returns the token as a [`FooterToken`], for matching on well-known
+    /// tokens.
+    pub fn footer_token(&self) -> FooterToken<'a> {
+        FooterToken::from(self.token)
+    }
+
+    /// Returns `true` if the footer has the given token, as the specification
+    /// compares them.
+    ///
+    /// Tokens are compared case-insensitively, except for `BREAKING CHANGE`,
+    /// which has to be uppercase in the footer. `BREAKING CHANGE` and
+    /// `BREAKING-CHANGE` are synonyms, so either one finds both.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::{Footer, FooterSeparator};
+    ///
+    /// let footer = Footer::from("Co-Authored-By", FooterSeparator::ColonSpace, "Jane");
+    /// assert!(footer.has_token("co-authored-by"));
+    ///
+    /// let footer = Footer::from("BREAKING-CHANGE", FooterSeparator::ColonSpace, "v2");
+    /// assert!(footer.has_token("BREAKING CHANGE"));
+    /// ```
+    pub fn has_token(&self, token: &str) -> bool {
+        if token.eq_ignore_ascii_case(BREAKING_CHANGE_TOKEN)
+            || token.eq_ignore_ascii_case(BREAKING_CHANGE_TOKEN_ALIAS)
+        {
+            self.is_breaking_change()
+        } else {
+            self.token.eq_ignore_ascii_case(token)
+        }
+    }
+
+    /// Parses the value as `Name <email>`, as used by footers like
+    /// `Signed-off-by` or `Co-authored-by`.
+    ///
+    /// See [`Person::parse`] for details.
+    pub fn person(&self) -> Option<Person<'a>> {
+        Person::parse(self.value)
+    }
+
+    /// Returns the `http://` and `https://` links in the value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::Commit;
+    ///
+    /// let commit = Commit::parse("feat: add a parser\n\nSee-also: https://example.com/design.").unwrap();
+    /// assert_eq!(commit.footer[0].links(), ["https://example.com/design"]);
+    /// ```
+    pub fn links(&self) -> Vec<&'a str> {
+        link::links(self.value)
+    }
+
+    /// Returns the links in the value that are valid URLs.
+    #[cfg(feature = "url")]
+    pub fn urls(&self) -> Vec<url::Url> {
+        self.links()
+            .into_iter()
+            .filter_map(|link| url::Url::parse(link).ok())
+            .collect()
+    }
+
+    /// Returns `true` if the footer marks the commit as a breaking change.
+    ///
+    /// Both `BREAKING CHANGE` and its synonym `BREAKING-CHANGE` are
+    /// recognized. As required by the specification, the tokens have to be
+    /// uppercase.
+    pub fn is_breaking_change(&self) -> bool {
+        self.token == BREAKING_CHANGE_TOKEN || self.token == BREAKING_CHANGE_TOKEN_ALIAS
+    }
+
+    /// Returns an iterator over the lines of the value.
+    ///
+    /// Line breaks, including carriage returns, are not part of the lines.
+    pub fn value_lines(&self) -> impl Iterator<Item = &'a str> {
+        self.value.split('\n').map(parser::strip_cr)
+    }
+}
+
+/// Whether footer tokens are compared with or without regard to their case.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum CaseSensitivity {
+    /// Tokens have to match exactly.
+    Sensitive,
+    /// Tokens match regardless of the case of their letters.
+    Insensitive,
+}
+
+impl Default for CaseSensitivity {
+    /// Returns the default CaseSensitivity, the Sensitive.
+    fn default() -> Self {
+        CaseSensitivity::Sensitive
+    }
+}
+
+impl fmt::Display for Footer<'_> {
+    /// Formats the footer as its token, separator and value, like
+    /// `Refs: #123`. The lines of a value that spans multiple lines are
+    /// separated by `\n`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format::write_footer(f, self, LineEnding::Lf.as_str())
+    }
 }
 
 /// The separator used to separate the token and value of a footer.
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
-pub enum FooterSeparator {
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum FooterSeparator<'a> {
     /// The `:<space>` separator is mostly used for values that do not involve
     /// issue or PR references.
     ColonSpace,
     /// The `#<space>` separator is, more often than not, used for issue and PR
     /// references.
     SpaceHashTag,
+    /// Any other separator, as written in the message, like `=<space>`.
+    ///
+    /// Only returned by the parser if it is configured to accept the
+    /// separator, see [`ParseOptions::footer_separators`].
+    Custom(&'a str),
 }
 
-impl Default for FooterSeparator {
+impl<'a> FooterSeparator<'a> {
+    /// Returns the separator as written in a message.
+    pub fn as_str(&self) -> &'a str {
+        match self {
+            FooterSeparator::ColonSpace => SEPARATOR_COLON,
+            FooterSeparator::SpaceHashTag => SEPARATOR_HASHTAG,
+            FooterSeparator::Custom(separator) => separator,
+        }
+    }
+}
+
+impl Default for FooterSeparator<'_> {
     /// Returns the default FooterSeparator, the ColonSpace.
     fn default() -> Self {
         FooterSeparator::ColonSpace
     }
 }
 
-impl fmt::Display for FooterSeparator {
+impl fmt::Display for FooterSeparator<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            FooterSeparator::ColonSpace => write!(f, "{}", SEPARATOR_COLON),
-            FooterSeparator::SpaceHashTag => write!(f, "{}", SEPARATOR_HASHTAG),
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FooterSeparator<'a> {
+    /// Returns the known separator that `s` is, or a custom one.
+    fn from(s: &'a str) -> Self {
+        match s {
+            SEPARATOR_COLON => FooterSeparator::ColonSpace,
+            SEPARATOR_HASHTAG => FooterSeparator::SpaceHashTag,
+            _ => FooterSeparator::Custom(s),
         }
     }
 }
 
-impl FromStr for FooterSeparator {
-    type Err = String;
+impl FromStr for FooterSeparator<'_> {
+    type Err = ParseError;
 
+    /// Parses one of the separators of the specification. Custom separators
+    /// borrow from their input and are created with `From<&str>` instead.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             SEPARATOR_COLON => Ok(FooterSeparator::ColonSpace),
             SEPARATOR_HASHTAG => Ok(FooterSeparator::SpaceHashTag),
-            _ => Err("footer separator not recognized".to_string()),
+            _ => Err(ParseError::new(ParseErrorKind::UnknownFooterSeparator)),
         }
     }
 }
@@ -0,0 +1,39 @@
+/// The schemes of the links that are recognized.
+const SCHEMES: &[&str] = &["https://", "http://"];
+
+/// Returns the `http://` and `https://` links in a text.
+pub(crate) fn links(text: &str) -> Vec<&str> {
+    let mut links = Vec::new();
+    let mut idx = 0;
+    while let Some(start) = find_link(&text[idx..]) {
+        let start = idx + start;
+        let len = link_len(&text[start..]);
+        links.push(&text[start..start + len]);
+        idx = start + len;
+    }
+    links
+}
+
+/// Returns the offset of the first link in `text`.
+fn find_link(text: &str) -> Option<usize> {
+    SCHEMES.iter().filter_map(|scheme| text.find(scheme)).min()
+}
+
+/// Returns the length of the link at the start of `text`.
+///
+/// A link ends at whitespace or at a closing bracket or quote. Punctuation at
+/// its end, like the period that ends a sentence, is not part of the link.
+pub(crate) fn link_len(text: &str) -> usize {
+    let end = text
+        .find(|c: char| c.is_whitespace() || c == '>' || c == ')' || c == '"' || c == '\'')
+        .unwrap_or(text.len());
+    text[..end]
+        .trim_end_matches(|c| c == '.' || c == ',' || c == ';' || c == ':' || c == '!' || c == '?')
+        .len()
+}
+
+/// Returns `true` if `separator` and `value` are the colon and the rest of a
+/// link, like in `https://example.com`, instead of a footer.
+pub(crate) fn is_link_separator(separator: &str, value: &str) -> bool {
+    separator.ends_with(':') && value.starts_with("//")
+}
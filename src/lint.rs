@@ -0,0 +1,533 @@
+//! Lints commit messages with a configurable set of rules, as commit-msg
+//! hooks and CI bots do.
+//!
+//! Every [`Rule`] looks at the parsed message and reports the
+//! [`Violation`]s it finds. [`lint`] runs the [`default_rules`], which check
+//! the specification and the conventions enabled by the [`Config`];
+//! [`lint_with`] runs any other rules, including ones written outside of
+//! this crate.
+//!
+//! # Example
+//!
+//! ```
+//! use conventional_commits_types::lint::{self, Config};
+//!
+//! let report = lint::lint("feat add a parser", &Config::default());
+//! assert!(!report.is_ok());
+//! assert_eq!(report.violations()[0].rule(), "syntax");
+//!
+//! assert!(lint::lint("feat: add a parser", &Config::default()).is_ok());
+//! ```
+
+mod autofix;
+mod commitlint;
+mod config;
+mod github;
+#[cfg(feature = "imperative")]
+mod imperative;
+mod rules;
+mod sarif;
+mod suggest;
+mod toml;
+
+pub use autofix::{fix, AppliedFix};
+pub use config::{Case, CaseRule, Config, ConfigError, CONFIG_FILE_NAME};
+pub use github::GithubAnnotations;
+#[cfg(feature = "imperative")]
+pub use imperative::ImperativeMood;
+pub use rules::{
+    BodyMaxLineLength, FooterTokenSpelling, HeaderMaxLength, RequiredFooters, ScopeCase, ScopeEnum,
+    SubjectCase, SubjectFullStop, Syntax, TypeEnum,
+};
+
+use crate::{error, parser, Commit, CommitSpans, ParseError, Span};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::{error::Error, fmt, str::FromStr};
+
+/// Everything a [`Rule`] can look at.
+#[derive(Clone, Copy, Debug)]
+pub struct LintContext<'a> {
+    /// The linted message.
+    pub input: &'a str,
+    /// The commit, as far as the message could be parsed.
+    pub commit: &'a Commit<'a>,
+    /// The locations of the components of the commit in the message.
+    pub spans: &'a CommitSpans,
+    /// The problems found while parsing the message.
+    pub errors: &'a [ParseError],
+    /// The settings of the lint.
+    pub config: &'a Config,
+}
+
+impl<'a> LintContext<'a> {
+    /// Returns the text of the message that `span` covers.
+    pub fn text(&self, span: Span) -> &'a str {
+        &self.input[span.start..span.end]
+    }
+
+    /// Returns a violation of `rule` that covers `span` of the message.
+    pub fn violation(
+        &self,
+        rule: &'static str,
+        message: impl Into<String>,
+        span: Span,
+    ) -> Violation {
+        Violation::at(rule, message, self.input, span)
+    }
+}
+
+/// A check of commit messages.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::lint::{self, Config, LintContext, Rule, Violation};
+///
+/// #[derive(Debug)]
+/// struct NoFixme;
+///
+/// impl Rule for NoFixme {
+///     fn id(&self) -> &'static str {
+///         "no-fixme"
+///     }
+///
+///     fn check(&self, context: &LintContext<'_>, violations: &mut Vec<Violation>) {
+///         if context.commit.desc.contains("FIXME") {
+///             violations.push(context.violation(self.id(), "remove the FIXME", context.spans.desc));
+///         }
+///     }
+/// }
+///
+/// let rules: Vec<Box<dyn Rule>> = vec![Box::new(NoFixme)];
+/// let report = lint::lint_with("feat: FIXME add a parser", &Config::default(), &rules);
+/// assert_eq!(report.violations()[0].message(), "remove the FIXME");
+/// ```
+pub trait Rule: fmt::Debug {
+    /// Returns the identifier of the rule, like `header-max-length`.
+    ///
+    /// Where there is one, the name of the equivalent commitlint rule is
+    /// used.
+    fn id(&self) -> &'static str;
+
+    /// Returns the severity of the violations of the rule.
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    /// Checks a message, pushing the violations it finds onto `violations`.
+    fn check(&self, context: &LintContext<'_>, violations: &mut Vec<Violation>);
+}
+
+/// How much a [`Violation`] matters.
+///
+/// The severity of a rule can be changed with [`Config::severities`],
+/// including to [`Severity::Off`] to not run it at all.
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "lowercase"))]
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Severity {
+    /// The rule is not run. Violations never have this severity.
+    Off,
+    /// The message should be improved, but can be used as it is.
+    Warning,
+    /// The message has to be changed.
+    Error,
+}
+
+impl Severity {
+    /// Returns the name of the severity, like `warning`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Severity::Off => "off",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Severity {
+    type Err = ();
+
+    /// Parses the name of a severity, like `warning`, or the level of a
+    /// commitlint rule, `0`, `1` or `2`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" | "0" => Ok(Severity::Off),
+            "warning" | "1" => Ok(Severity::Warning),
+            "error" | "2" => Ok(Severity::Error),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A change of the message that resolves a [`Violation`], replacing a span
+/// of it.
+///
+/// Fixes are safe if they keep the meaning of the message, like removing a
+/// full stop, and only those are applied by [`fix`]. Others, created with
+/// [`Fix::suggestion`], may need a human to check them.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{lint::Fix, Span};
+///
+/// let fix = Fix::new(Span::new(18, 19), "");
+/// assert_eq!(fix.apply("feat: add a parser."), "feat: add a parser");
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Fix {
+    span: Span,
+    replacement: String,
+    is_safe: bool,
+}
+
+impl Fix {
+    /// Creates a safe fix that replaces `span` with `replacement`.
+    pub fn new(span: Span, replacement: impl Into<String>) -> Self {
+        Self {
+            span,
+            replacement: replacement.into(),
+            is_safe: true,
+        }
+    }
+
+    /// Creates a fix that replaces `span` with `replacement`, but is not
+    /// safe to apply without checking it.
+    pub fn suggestion(span: Span, replacement: impl Into<String>) -> Self {
+        Self {
+            is_safe: false,
+            ..Self::new(span, replacement)
+        }
+    }
+
+    /// Returns `true` if the fix can be applied without checking it.
+    pub fn is_safe(&self) -> bool {
+        self.is_safe
+    }
+
+    /// Returns the byte range of the message that is replaced.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Returns the text that replaces the span.
+    pub fn replacement(&self) -> &str {
+        &self.replacement
+    }
+
+    /// Returns `input` with the fix applied.
+    pub fn apply(&self, input: &str) -> String {
+        let mut output = input.to_string();
+        output.replace_range(self.span.start..self.span.end, &self.replacement);
+        output
+    }
+}
+
+/// A violation of a [`Rule`].
+///
+/// Like a [`ParseError`], a violation records where in the message it was
+/// found, both as a byte range and as a line and column. It may come with a
+/// [`Fix`] that resolves it.
+///
+/// With the `miette` feature, it implements `miette::Diagnostic`: the rule is
+/// its code, and its message labels its span of the linted message, which is
+/// attached with `Report::with_source_code`. The feature requires Rust 1.70.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "miette")]
+/// # {
+/// use conventional_commits_types::lint::{self, Config};
+/// use miette::{Diagnostic, Report};
+///
+/// let message = "feat: add a parser.";
+/// let violation = lint::lint(message, &Config::default()).violations()[0].clone();
+/// assert_eq!(violation.code().unwrap().to_string(), "subject-full-stop");
+/// assert_eq!(violation.help().unwrap().to_string(), "remove it");
+///
+/// let label = violation.labels().unwrap().next().unwrap();
+/// assert_eq!(label.label(), Some("description must not end with a full stop"));
+/// assert_eq!((label.offset(), label.len()), (18, 1));
+///
+/// let report = Report::new(violation).with_source_code(message);
+/// assert!(report.source_code().is_some());
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Violation {
+    rule: &'static str,
+    message: String,
+    span: Span,
+    line: usize,
+    column: usize,
+    severity: Severity,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    fix: Option<Fix>,
+}
+
+impl Violation {
+    /// Creates a violation of `rule` that covers `span` of `input`.
+    pub fn at(rule: &'static str, message: impl Into<String>, input: &str, span: Span) -> Self {
+        let (line, column) = error::location(input, span.start);
+        Self {
+            rule,
+            message: message.into(),
+            span,
+            line,
+            column,
+            severity: Severity::Error,
+            fix: None,
+        }
+    }
+
+    /// Attaches a fix that resolves the violation.
+    pub fn with_fix(mut self, fix: Fix) -> Self {
+        self.fix = Some(fix);
+        self
+    }
+
+    /// Returns the identifier of the violated rule.
+    pub fn rule(&self) -> &'static str {
+        self.rule
+    }
+
+    /// Returns the explanation of the violation.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the byte range of the message that violates the rule.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Returns the line at which the violation occurred, starting at 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column at which the violation occurred, starting at 1.
+    ///
+    /// Columns are counted in characters, not bytes.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns the severity of the violation, the one of its rule when
+    /// [`lint`] reported it, see [`Config::severity`].
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Returns the fix that resolves the violation, if the rule knows one.
+    pub fn fix(&self) -> Option<&Fix> {
+        self.fix.as_ref()
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}: {} [{}]",
+            self.line, self.column, self.severity, self.message, self.rule
+        )
+    }
+}
+
+impl Error for Violation {}
+
+/// The violations found by [`lint`], in the order in which they appear in
+/// the message.
+///
+/// With the `serde` feature, it is serialized for bots and editors, with the
+/// rule, span, severity and fix of every violation.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use conventional_commits_types::lint::{self, Config};
+///
+/// let report = lint::lint("feat: add a parser.", &Config::default());
+/// assert_eq!(
+///     serde_json::to_string(&report).unwrap(),
+///     r#"{"violations":[{"rule":"subject-full-stop","message":"description must not end with a full stop","span":{"start":18,"end":19},"line":1,"column":19,"severity":"error","fix":{"span":{"start":18,"end":19},"replacement":"","is_safe":true}}]}"#
+/// );
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct LintReport {
+    violations: Vec<Violation>,
+}
+
+impl LintReport {
+    /// Returns `true` if no rule was violated.
+    pub fn is_ok(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Returns `true` if any violation is an error, which CI usually fails
+    /// for.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::lint::{self, Config, Severity};
+    ///
+    /// let mut config = Config::default();
+    /// config.severities.insert("type-enum".to_string(), Severity::Warning);
+    ///
+    /// let report = lint::lint("release: bump the version", &config);
+    /// assert!(!report.is_ok());
+    /// assert!(!report.has_errors());
+    /// assert_eq!(report.warnings().count(), 1);
+    /// ```
+    pub fn has_errors(&self) -> bool {
+        self.errors().next().is_some()
+    }
+
+    /// Returns the violations.
+    pub fn violations(&self) -> &[Violation] {
+        &self.violations
+    }
+
+    /// Returns the violations that are errors.
+    pub fn errors(&self) -> impl Iterator<Item = &Violation> {
+        self.with_severity(Severity::Error)
+    }
+
+    /// Returns the violations that are warnings.
+    pub fn warnings(&self) -> impl Iterator<Item = &Violation> {
+        self.with_severity(Severity::Warning)
+    }
+
+    /// Returns the report as a SARIF 2.1.0 log, for GitHub code scanning and
+    /// other SARIF consumers.
+    ///
+    /// The violations are located in the artifact at `uri`, like the file
+    /// the message was read from, and come with their fixes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::lint::{self, Config};
+    ///
+    /// let report = lint::lint("feat: add a parser.", &Config::default());
+    /// let sarif = report.to_sarif(".git/COMMIT_EDITMSG");
+    /// assert!(sarif.starts_with(r#"{"version":"2.1.0","#));
+    /// assert!(sarif.contains(r#""ruleId":"subject-full-stop","ruleIndex":0,"level":"error""#));
+    /// assert!(sarif.contains(r#""region":{"startLine":1,"startColumn":19,"byteOffset":18,"byteLength":1}"#));
+    /// ```
+    pub fn to_sarif(&self, uri: &str) -> String {
+        let mut sarif = String::new();
+        sarif::write_sarif(&mut sarif, self, uri).expect("writing to a String cannot fail");
+        sarif
+    }
+
+    /// Returns the report displayed as GitHub Actions workflow commands that
+    /// annotate `file`, the file the message was read from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::lint::{self, Config};
+    ///
+    /// let report = lint::lint("feat: add a parser.", &Config::default());
+    /// assert_eq!(
+    ///     report.github_annotations(".git/COMMIT_EDITMSG").to_string(),
+    ///     "::error file=.git/COMMIT_EDITMSG,line=1,col=19,title=subject-full-stop::\
+    ///      description must not end with a full stop\n"
+    /// );
+    /// ```
+    pub fn github_annotations<'r>(&'r self, file: &'r str) -> GithubAnnotations<'r> {
+        GithubAnnotations::new(self, file)
+    }
+
+    fn with_severity(&self, severity: Severity) -> impl Iterator<Item = &Violation> {
+        self.violations
+            .iter()
+            .filter(move |violation| violation.severity == severity)
+    }
+}
+
+impl fmt::Display for LintReport {
+    /// Formats the report with one violation per line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for violation in &self.violations {
+            writeln!(f, "{}", violation)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the rules that [`lint`] runs.
+///
+/// Rules whose setting in the [`Config`] is not set do not report anything.
+/// With the `imperative` feature, `ImperativeMood` is included as well.
+pub fn default_rules() -> Vec<Box<dyn Rule>> {
+    #[allow(unused_mut)]
+    let mut rules: Vec<Box<dyn Rule>> = vec![
+        Box::new(Syntax),
+        Box::new(TypeEnum),
+        Box::new(ScopeEnum),
+        Box::new(ScopeCase),
+        Box::new(HeaderMaxLength),
+        Box::new(SubjectCase),
+        Box::new(SubjectFullStop),
+        Box::new(BodyMaxLineLength),
+        Box::new(RequiredFooters),
+        Box::new(FooterTokenSpelling),
+    ];
+    #[cfg(feature = "imperative")]
+    rules.push(Box::new(ImperativeMood));
+    rules
+}
+
+/// Lints a message with the [`default_rules`].
+pub fn lint(input: &str, config: &Config) -> LintReport {
+    lint_with(input, config, &default_rules())
+}
+
+/// Lints a message with the given rules.
+///
+/// The message is parsed with [`Config::options`]. Rules also run if it
+/// could not be parsed, on the best-effort interpretation of the parser,
+/// unless their [`Config::severity`] is [`Severity::Off`].
+pub fn lint_with(input: &str, config: &Config, rules: &[Box<dyn Rule>]) -> LintReport {
+    let mut errors = Vec::new();
+    let (commit, spans) = parser::parse(input, &config.options, &mut errors);
+    let context = LintContext {
+        input,
+        commit: &commit,
+        spans: &spans,
+        errors: &errors,
+        config,
+    };
+    let mut violations = Vec::new();
+    for rule in rules {
+        let severity = config.severity(rule.as_ref());
+        if severity == Severity::Off {
+            continue;
+        }
+        let start = violations.len();
+        rule.check(&context, &mut violations);
+        for violation in &mut violations[start..] {
+            violation.severity = severity;
+        }
+    }
+    violations.sort_by_key(|violation| violation.span.start);
+    LintReport { violations }
+}
@@ -0,0 +1,117 @@
+use super::{default_rules, lint_with, Config, Fix, Violation};
+use crate::ParseOptions;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// The number of times the message is linted again after fixing it, since
+/// fixes that overlap are applied one after the other.
+const MAX_PASSES: usize = 8;
+
+/// A fix that [`fix`] applied.
+///
+/// With the `serde` feature, it is serialized as the violation it fixed.
+#[cfg_attr(feature = "serde", derive(Serialize), serde(transparent))]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct AppliedFix {
+    violation: Violation,
+}
+
+impl AppliedFix {
+    /// Returns the identifier of the rule whose violation was fixed.
+    pub fn rule(&self) -> &'static str {
+        self.violation.rule()
+    }
+
+    /// Returns the explanation of the violation that was fixed.
+    pub fn message(&self) -> &str {
+        self.violation.message()
+    }
+
+    /// Returns the fix.
+    ///
+    /// Its span refers to the message as it was when the fix was applied,
+    /// after the fixes before it.
+    pub fn fix(&self) -> &Fix {
+        self.violation
+            .fix()
+            .expect("only violations with fixes are applied")
+    }
+}
+
+/// Applies the safe fixes of the [`default_rules`] to a message, returning
+/// the fixed message and the fixes in the order they were applied.
+///
+/// The message is parsed strictly, so that the spacing of the separator and
+/// footer tokens with spaces are fixed even where [`Config::options`] would
+/// accept them. Fixed are:
+///
+/// - the spacing around the `: ` separator and leading or trailing whitespace
+///   of components,
+/// - uppercase letters in the type,
+/// - spaces in footer tokens, which become hyphens,
+/// - the case of the first letter of the description, if that is all it takes
+///   to satisfy [`Config::subject_case`],
+/// - a full stop at the end of the description.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::lint::{self, Case, CaseRule, Config};
+///
+/// let config = Config {
+///     subject_case: Some(CaseRule::Always(vec![Case::Lower])),
+///     ..Config::default()
+/// };
+/// let (message, fixes) = lint::fix(
+///     "Feat :  Add a parser.\n\nSigned off by: Jane <jane@example.com>",
+///     &config,
+/// );
+/// assert_eq!(message, "feat: add a parser\n\nSigned-off-by: Jane <jane@example.com>");
+/// assert_eq!(fixes[0].rule(), "syntax");
+/// ```
+pub fn fix(input: &str, config: &Config) -> (String, Vec<AppliedFix>) {
+    let config = Config {
+        options: ParseOptions {
+            strict: true,
+            ..config.options.clone()
+        },
+        ..config.clone()
+    };
+    let rules = default_rules();
+    let mut message = input.to_string();
+    let mut applied = Vec::new();
+    for _ in 0..MAX_PASSES {
+        let report = lint_with(&message, &config, &rules);
+        let mut fixes: Vec<_> = report
+            .violations()
+            .iter()
+            .filter(|violation| violation.fix().map_or(false, Fix::is_safe))
+            .cloned()
+            .collect();
+        fixes.sort_by_key(|violation| {
+            violation
+                .fix()
+                .map(|fix| (fix.span().start, fix.span().end))
+        });
+        // Of overlapping fixes, only the first is applied in this pass.
+        let mut end = 0;
+        fixes.retain(|violation| {
+            let span = violation.fix().map(Fix::span).unwrap_or_default();
+            let keep = span.start >= end;
+            if keep {
+                end = span.end.max(span.start + 1);
+            }
+            keep
+        });
+        if fixes.is_empty() {
+            break;
+        }
+        for violation in fixes.iter().rev() {
+            if let Some(fix) = violation.fix() {
+                message.replace_range(fix.span().start..fix.span().end, fix.replacement());
+            }
+        }
+        applied.extend(fixes.into_iter().map(|violation| AppliedFix { violation }));
+    }
+    (message, applied)
+}
@@ -0,0 +1,742 @@
+//! A reader for the JSON and YAML forms of commitlint configurations.
+//!
+//! JSON is read as YAML flow collections, so a single parser covers both.
+//! Of YAML, block mappings and sequences, flow collections, quoted and plain
+//! scalars and comments are supported; anchors, aliases, tags and
+//! multi-line scalars are rejected.
+
+use super::{Case, CaseRule, Config, ConfigError, Severity};
+use crate::DEFAULT_TYPES;
+
+/// The shared configuration that commitlint configurations usually extend.
+const CONFIG_CONVENTIONAL: &str = "@commitlint/config-conventional";
+
+/// A node of a document.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Node {
+    Scalar(String),
+    Sequence(Vec<Node>),
+    Mapping(Vec<(String, Node)>),
+}
+
+impl Node {
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Node::Scalar(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn get(&self, key: &str) -> Option<&Node> {
+        match self {
+            Node::Mapping(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// Returns the strings of a sequence, or the string of a scalar.
+    fn strings(&self) -> Vec<String> {
+        match self {
+            Node::Scalar(s) => vec![s.clone()],
+            Node::Sequence(nodes) => nodes
+                .iter()
+                .filter_map(Node::as_str)
+                .map(str::to_string)
+                .collect(),
+            Node::Mapping(_) => Vec::new(),
+        }
+    }
+}
+
+/// A line of a document without its comment.
+struct Line<'a> {
+    number: usize,
+    indent: usize,
+    text: &'a str,
+}
+
+/// Parses a commitlint configuration into a [`Config`], along with the names
+/// of the enabled rules that it cannot express.
+pub(crate) fn parse(input: &str) -> Result<(Config, Vec<String>), ConfigError> {
+    let lines: Vec<_> = input
+        .lines()
+        .enumerate()
+        .filter_map(|(idx, line)| {
+            let text = strip_comment(line).trim_end();
+            let trimmed = text.trim_start();
+            if trimmed.is_empty() || trimmed == "---" {
+                None
+            } else {
+                Some(Line {
+                    number: idx + 1,
+                    indent: text.len() - trimmed.len(),
+                    text: trimmed,
+                })
+            }
+        })
+        .collect();
+    let document = if lines.is_empty() {
+        Node::Mapping(Vec::new())
+    } else {
+        let mut pos = 0;
+        let node = parse_block(&lines, &mut pos, lines[0].indent)?;
+        if let Some(line) = lines.get(pos) {
+            return Err(invalid(line.number, "unexpected indentation"));
+        }
+        node
+    };
+
+    // Without extending another configuration, commitlint checks nothing.
+    let mut config = Config {
+        types: None,
+        header_max_length: None,
+        body_max_line_length: None,
+        subject_full_stop: false,
+        ..Config::default()
+    };
+    let extends = document.get("extends").map_or_else(Vec::new, Node::strings);
+    if extends.iter().any(|name| name == CONFIG_CONVENTIONAL) {
+        config.types = Some(DEFAULT_TYPES.iter().map(|ty| ty.to_string()).collect());
+        config.header_max_length = Some(100);
+        config.body_max_line_length = Some(100);
+        config.scope_case = Some(CaseRule::Always(vec![Case::Lower]));
+        config.subject_full_stop = true;
+        config.subject_case = Some(CaseRule::Never(vec![
+            Case::Sentence,
+            Case::Start,
+            Case::Pascal,
+            Case::Upper,
+        ]));
+    }
+    let mut ignored = Vec::new();
+    if let Some(Node::Mapping(rules)) = document.get("rules") {
+        for (name, rule) in rules {
+            if !apply_rule(&mut config, name, rule) {
+                ignored.push(name.clone());
+            }
+        }
+    }
+    Ok((config, ignored))
+}
+
+/// Applies a rule of the form `[level, applicable, value]` to the config.
+///
+/// Returns `false` if the rule is enabled but cannot be expressed by the
+/// config, in which case it is ignored.
+fn apply_rule(config: &mut Config, name: &str, rule: &Node) -> bool {
+    let parts = match rule {
+        Node::Sequence(parts) => parts,
+        _ => return false,
+    };
+    let level = parts.first().and_then(Node::as_str);
+    let enabled = level.map_or(false, |level| level != "0");
+    let always = parts.get(1).and_then(Node::as_str) != Some("never");
+    let value = parts.get(2);
+    let number = value
+        .and_then(Node::as_str)
+        .and_then(|value| value.parse().ok());
+    let cases: Vec<Case> = value
+        .map_or_else(Vec::new, Node::strings)
+        .iter()
+        .filter_map(|case| case.parse().ok())
+        .collect();
+    let case = if !enabled || cases.is_empty() {
+        None
+    } else if always {
+        Some(CaseRule::Always(cases))
+    } else {
+        Some(CaseRule::Never(cases))
+    };
+
+    let applied = match name {
+        "type-enum" if always => {
+            config.types = if enabled {
+                value.map(Node::strings)
+            } else {
+                None
+            };
+            true
+        }
+        "scope-enum" if always => {
+            config.scopes = if enabled {
+                value.map(Node::strings).filter(|scopes| !scopes.is_empty())
+            } else {
+                None
+            };
+            true
+        }
+        "header-max-length" if always && (number.is_some() || !enabled) => {
+            config.header_max_length = if enabled { number } else { None };
+            true
+        }
+        "body-max-line-length" if always && (number.is_some() || !enabled) => {
+            config.body_max_line_length = if enabled { number } else { None };
+            true
+        }
+        "subject-case" if case.is_some() || !enabled => {
+            config.subject_case = case;
+            true
+        }
+        "subject-full-stop" if !always || !enabled => {
+            config.subject_full_stop = enabled
+                && value
+                    .and_then(Node::as_str)
+                    .map_or(true, |stop| stop == ".");
+            true
+        }
+        "scope-case" if case.is_some() || !enabled => {
+            config.scope_case = case;
+            true
+        }
+        "trailer-exists" if always => {
+            config.required_footers = if enabled {
+                value.map_or_else(Vec::new, Node::strings)
+            } else {
+                Vec::new()
+            };
+            true
+        }
+        _ => !enabled,
+    };
+    if applied && level == Some("1") {
+        config
+            .severities
+            .insert(name.to_string(), Severity::Warning);
+    }
+    applied
+}
+
+fn invalid(line: usize, message: &str) -> ConfigError {
+    ConfigError::Invalid {
+        line,
+        message: message.to_string(),
+    }
+}
+
+/// Removes a comment, which starts with a `#` outside of quotes that is at
+/// the start of the line or follows whitespace.
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut previous = ' ';
+    for (idx, c) in line.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '#' && previous.is_whitespace() => return &line[..idx],
+            None => {}
+        }
+        previous = c;
+    }
+    line
+}
+
+/// Parses the block that starts at `lines[*pos]` and is indented by
+/// `indent`.
+fn parse_block(lines: &[Line<'_>], pos: &mut usize, indent: usize) -> Result<Node, ConfigError> {
+    let first = &lines[*pos];
+    if first.text.starts_with('[') || first.text.starts_with('{') {
+        return parse_flow_lines(lines, pos);
+    }
+    if is_sequence_item(first.text) {
+        let mut items = Vec::new();
+        while let Some(line) = lines.get(*pos) {
+            if line.indent != indent || !is_sequence_item(line.text) {
+                break;
+            }
+            let rest = line.text[1..].trim_start();
+            if rest.is_empty() {
+                *pos += 1;
+                items.push(parse_nested(lines, pos, indent)?);
+            } else if split_key(rest).is_some() && !rest.starts_with(|c| c == '[' || c == '{') {
+                // A mapping that starts on the line of the item.
+                let item_indent = line.indent + (line.text.len() - rest.len());
+                let mut shifted: Vec<_> = vec![Line {
+                    number: line.number,
+                    indent: item_indent,
+                    text: rest,
+                }];
+                *pos += 1;
+                while let Some(next) = lines.get(*pos) {
+                    if next.indent < item_indent {
+                        break;
+                    }
+                    shifted.push(Line {
+                        number: next.number,
+                        indent: next.indent,
+                        text: next.text,
+                    });
+                    *pos += 1;
+                }
+                let mut inner = 0;
+                items.push(parse_block(&shifted, &mut inner, item_indent)?);
+            } else {
+                let item = Line {
+                    number: line.number,
+                    indent: line.indent,
+                    text: rest,
+                };
+                *pos += 1;
+                items.push(parse_inline(&item, lines, pos)?);
+            }
+        }
+        return Ok(Node::Sequence(items));
+    }
+    if split_key(first.text).is_none() {
+        *pos += 1;
+        return parse_scalar(first.text, first.number).map(Node::Scalar);
+    }
+
+    let mut entries = Vec::new();
+    while let Some(line) = lines.get(*pos) {
+        if line.indent < indent {
+            break;
+        }
+        if line.indent > indent {
+            return Err(invalid(line.number, "unexpected indentation"));
+        }
+        let (key, rest) =
+            split_key(line.text).ok_or_else(|| invalid(line.number, "expected `key: value`"))?;
+        let key = parse_scalar(key, line.number)?;
+        *pos += 1;
+        let value = if rest.is_empty() {
+            // A sequence may be written at the indentation of its key.
+            match lines.get(*pos) {
+                Some(next) if next.indent == indent && is_sequence_item(next.text) => {
+                    parse_block(lines, pos, indent)?
+                }
+                _ => parse_nested(lines, pos, indent)?,
+            }
+        } else {
+            let value = Line {
+                number: line.number,
+                indent: line.indent,
+                text: rest,
+            };
+            parse_inline(&value, lines, pos)?
+        };
+        entries.push((key, value));
+    }
+    Ok(Node::Mapping(entries))
+}
+
+/// Parses the block nested more deeply than `indent`, or an empty scalar if
+/// there is none.
+fn parse_nested(lines: &[Line<'_>], pos: &mut usize, indent: usize) -> Result<Node, ConfigError> {
+    match lines.get(*pos) {
+        Some(next) if next.indent > indent => parse_block(lines, pos, next.indent),
+        _ => Ok(Node::Scalar(String::new())),
+    }
+}
+
+/// Parses a value on the line of its key or sequence item, which may be a
+/// flow collection that continues on the following lines.
+fn parse_inline(line: &Line<'_>, lines: &[Line<'_>], pos: &mut usize) -> Result<Node, ConfigError> {
+    if line.text.starts_with('[') || line.text.starts_with('{') {
+        let mut text = line.text.to_string();
+        while !is_balanced(&text) {
+            let next = lines
+                .get(*pos)
+                .ok_or_else(|| invalid(line.number, "unterminated flow collection"))?;
+            text.push(' ');
+            text.push_str(next.text);
+            *pos += 1;
+        }
+        parse_flow(&text, line.number)
+    } else {
+        parse_scalar(line.text, line.number).map(Node::Scalar)
+    }
+}
+
+/// Parses a flow collection that starts at `lines[*pos]`, like a JSON
+/// document.
+fn parse_flow_lines(lines: &[Line<'_>], pos: &mut usize) -> Result<Node, ConfigError> {
+    let first = &lines[*pos];
+    *pos += 1;
+    parse_inline(first, lines, pos)
+}
+
+fn is_sequence_item(text: &str) -> bool {
+    text == "-" || text.starts_with("- ")
+}
+
+/// Splits `key: value` into the key and the value, outside of quotes.
+fn split_key(text: &str) -> Option<(&str, &str)> {
+    let mut quote = None;
+    for (idx, c) in text.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if idx == 0 && (c == '"' || c == '\'') => quote = Some(c),
+            None if c == ':' => {
+                let rest = &text[idx + 1..];
+                if rest.is_empty() || rest.starts_with(' ') {
+                    return Some((text[..idx].trim(), rest.trim()));
+                }
+            }
+            None => {}
+        }
+    }
+    None
+}
+
+/// Returns `true` if all brackets outside of quotes are closed.
+fn is_balanced(text: &str) -> bool {
+    let mut depth = 0i32;
+    let mut quote = None;
+    let mut escaped = false;
+    for c in text.chars() {
+        match quote {
+            Some(q) => {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' && q == '"' {
+                    escaped = true;
+                } else if c == q {
+                    quote = None;
+                }
+            }
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '[' | '{' => depth += 1,
+                ']' | '}' => depth -= 1,
+                _ => {}
+            },
+        }
+    }
+    depth <= 0
+}
+
+/// Parses a plain or quoted scalar.
+fn parse_scalar(text: &str, line: usize) -> Result<String, ConfigError> {
+    let text = text.trim();
+    let mut chars = text.chars().peekable();
+    match chars.peek() {
+        Some('"') | Some('\'') => {
+            let mut flow = Flow {
+                chars: text.char_indices().peekable(),
+                text,
+                line,
+            };
+            let scalar = flow.quoted()?;
+            if flow.chars.next().is_some() {
+                return Err(invalid(line, "unexpected text after a quoted scalar"));
+            }
+            Ok(scalar)
+        }
+        _ => plain(text, line),
+    }
+}
+
+/// Returns a plain scalar, rejecting the anchors, aliases, tags and
+/// multi-line scalars that start with an indicator.
+fn plain(text: &str, line: usize) -> Result<String, ConfigError> {
+    if text.starts_with(|c| ['&', '*', '!', '|', '>'].contains(&c)) {
+        Err(invalid(
+            line,
+            "anchors, aliases, tags and multi-line scalars are not supported",
+        ))
+    } else {
+        Ok(text.to_string())
+    }
+}
+
+/// Parses a flow collection.
+fn parse_flow(text: &str, line: usize) -> Result<Node, ConfigError> {
+    let mut flow = Flow {
+        chars: text.char_indices().peekable(),
+        text,
+        line,
+    };
+    let node = flow.node()?;
+    flow.skip_whitespace();
+    if flow.chars.next().is_some() {
+        return Err(invalid(line, "unexpected text after a flow collection"));
+    }
+    Ok(node)
+}
+
+/// A parser of flow collections.
+struct Flow<'a> {
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+    text: &'a str,
+    line: usize,
+}
+
+impl Flow<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.peek().map_or(false, |&(_, c)| c.is_whitespace()) {
+            self.chars.next();
+        }
+    }
+
+    fn node(&mut self) -> Result<Node, ConfigError> {
+        self.skip_whitespace();
+        match self.chars.peek().map(|&(_, c)| c) {
+            Some('[') => {
+                self.chars.next();
+                let mut nodes = Vec::new();
+                if !self.open(']') {
+                    loop {
+                        nodes.push(self.node()?);
+                        if self.close(']')? {
+                            break;
+                        }
+                    }
+                }
+                Ok(Node::Sequence(nodes))
+            }
+            Some('{') => {
+                self.chars.next();
+                let mut entries = Vec::new();
+                if self.open('}') {
+                    return Ok(Node::Mapping(entries));
+                }
+                loop {
+                    let key = match self.node()? {
+                        Node::Scalar(key) => key,
+                        _ => return Err(invalid(self.line, "keys must be scalars")),
+                    };
+                    self.skip_whitespace();
+                    if self.chars.next().map(|(_, c)| c) != Some(':') {
+                        return Err(invalid(self.line, "expected `:` after a key"));
+                    }
+                    entries.push((key, self.node()?));
+                    if self.close('}')? {
+                        break;
+                    }
+                }
+                Ok(Node::Mapping(entries))
+            }
+            Some('"') | Some('\'') => self.quoted().map(Node::Scalar),
+            Some(_) => {
+                let start = self.chars.peek().map_or(0, |&(idx, _)| idx);
+                let mut end = start;
+                while let Some(&(idx, c)) = self.chars.peek() {
+                    if c == ',' || c == ']' || c == '}' || (c == ':' && self.ends_key(idx)) {
+                        break;
+                    }
+                    end = idx + c.len_utf8();
+                    self.chars.next();
+                }
+                plain(self.text[start..end].trim(), self.line).map(Node::Scalar)
+            }
+            None => Err(invalid(self.line, "unterminated flow collection")),
+        }
+    }
+
+    /// Returns `true` if the `:` at `idx` ends a key, which it does when
+    /// followed by whitespace or the end of a collection.
+    fn ends_key(&self, idx: usize) -> bool {
+        self.text[idx + 1..].chars().next().map_or(true, |c| {
+            c.is_whitespace() || c == ',' || c == ']' || c == '}'
+        })
+    }
+
+    /// Consumes `end` if the collection is empty.
+    fn open(&mut self, end: char) -> bool {
+        self.skip_whitespace();
+        if self.chars.peek().map(|&(_, c)| c) == Some(end) {
+            self.chars.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes the separator after an entry, returning `true` if the
+    /// collection ended with `end`.
+    fn close(&mut self, end: char) -> Result<bool, ConfigError> {
+        self.skip_whitespace();
+        match self.chars.peek().map(|&(_, c)| c) {
+            Some(c) if c == end => {
+                self.chars.next();
+                Ok(true)
+            }
+            Some(',') => {
+                self.chars.next();
+                self.skip_whitespace();
+                if self.chars.peek().map(|&(_, c)| c) == Some(end) {
+                    self.chars.next();
+                    return Ok(true);
+                }
+                Ok(false)
+            }
+            Some(_) => Err(invalid(self.line, "expected `,` between entries")),
+            None => Err(invalid(self.line, "unterminated flow collection")),
+        }
+    }
+
+    /// Parses a quoted scalar, including its quotes.
+    fn quoted(&mut self) -> Result<String, ConfigError> {
+        let quote = self.chars.next().map_or('"', |(_, c)| c);
+        let mut scalar = String::new();
+        loop {
+            match self.chars.next().map(|(_, c)| c) {
+                Some('\'') if quote == '\'' => {
+                    // Single quotes are escaped by doubling them.
+                    if self.chars.peek().map(|&(_, c)| c) == Some('\'') {
+                        self.chars.next();
+                        scalar.push('\'');
+                    } else {
+                        return Ok(scalar);
+                    }
+                }
+                Some('"') if quote == '"' => return Ok(scalar),
+                Some('\\') if quote == '"' => match self.chars.next().map(|(_, c)| c) {
+                    Some('n') => scalar.push('\n'),
+                    Some('t') => scalar.push('\t'),
+                    Some('u') => {
+                        let hex: String = self.chars.by_ref().take(4).map(|(_, c)| c).collect();
+                        let c = u32::from_str_radix(&hex, 16)
+                            .ok()
+                            .and_then(std::char::from_u32)
+                            .ok_or_else(|| invalid(self.line, "invalid escape"))?;
+                        scalar.push(c);
+                    }
+                    Some(c) => scalar.push(c),
+                    None => return Err(invalid(self.line, "unterminated string")),
+                },
+                Some(c) => scalar.push(c),
+                None => return Err(invalid(self.line, "unterminated string")),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(input: &str) -> Config {
+        let (config, ignored) = parse(input).unwrap();
+        assert_eq!(ignored, Vec::<String>::new());
+        config
+    }
+
+    fn error(input: &str) -> String {
+        parse(input).unwrap_err().to_string()
+    }
+
+    fn strings(strings: &[&str]) -> Option<Vec<String>> {
+        Some(strings.iter().map(|s| s.to_string()).collect())
+    }
+
+    #[test]
+    fn an_empty_document_checks_nothing() {
+        for input in &["", "# only a comment\n---\n", "{}"] {
+            let config = config(input);
+            assert_eq!(config.types, None);
+            assert_eq!(config.header_max_length, None);
+            assert!(!config.subject_full_stop);
+        }
+    }
+
+    #[test]
+    fn json_and_yaml_are_read_alike() {
+        let json = r#"{
+            "extends": ["@commitlint/config-conventional"],
+            "rules": {
+                "type-enum": [2, "always", ["feat", "fix"]],
+                "header-max-length": [1, "always", 50],
+                "subject-full-stop": [0]
+            }
+        }"#;
+        let yaml = "extends:\n  - '@commitlint/config-conventional'\nrules:\n  type-enum:\n  - 2\n  - always\n  - [feat, fix]\n  header-max-length: [1, always, 50] # a warning\n  subject-full-stop: [0]\n";
+        let config = config(json);
+        assert_eq!(config, super::tests::config(yaml));
+        assert_eq!(config.types, strings(&["feat", "fix"]));
+        assert_eq!(config.header_max_length, Some(50));
+        assert_eq!(
+            config.severities.get("header-max-length"),
+            Some(&Severity::Warning)
+        );
+        assert!(!config.subject_full_stop);
+        assert_eq!(config.body_max_line_length, Some(100));
+    }
+
+    #[test]
+    fn extends_may_be_a_scalar() {
+        let config = config("extends: \"@commitlint/config-conventional\"");
+        assert_eq!(config.header_max_length, Some(100));
+        assert_eq!(config.scope_case, Some(CaseRule::Always(vec![Case::Lower])));
+    }
+
+    #[test]
+    fn rules_are_applied() {
+        let config = config(
+            "rules:\n  scope-enum: [2, always, [api, cli]]\n  scope-case: [2, never, [upper-case]]\n  subject-case: [2, always, sentence-case]\n  trailer-exists: [2, always, Signed-off-by]\n  subject-full-stop: [2, never, '!']\n",
+        );
+        assert_eq!(config.scopes, strings(&["api", "cli"]));
+        assert_eq!(config.scope_case, Some(CaseRule::Never(vec![Case::Upper])));
+        assert_eq!(
+            config.subject_case,
+            Some(CaseRule::Always(vec![Case::Sentence]))
+        );
+        assert_eq!(config.required_footers, ["Signed-off-by"]);
+        assert!(!config.subject_full_stop);
+    }
+
+    #[test]
+    fn rules_that_cannot_be_expressed_are_ignored() {
+        let input = "rules:\n  type-enum: [2, never, [wip]]\n  body-leading-blank: [1, always]\n  footer-leading-blank: [0, always]\n  header-max-length: [2, always, long]\n";
+        let (config, ignored) = parse(input).unwrap();
+        assert_eq!(
+            ignored,
+            ["type-enum", "body-leading-blank", "header-max-length"]
+        );
+        assert_eq!(config.types, None);
+        assert!(config.severities.is_empty());
+    }
+
+    #[test]
+    fn sequences_of_mappings() {
+        let input = "extends:\n- name: a\n  other: b\n- '@commitlint/config-conventional'\n";
+        assert_eq!(config(input).header_max_length, Some(100));
+    }
+
+    #[test]
+    fn scalars() {
+        assert_eq!(parse_scalar("'it''s'", 1).unwrap(), "it's");
+        assert_eq!(parse_scalar(r#""a\tbé""#, 1).unwrap(), "a\tbé");
+        assert_eq!(parse_scalar("a: b#c", 1).unwrap(), "a: b#c");
+        assert_eq!(strip_comment("a: 'b # c' # d"), "a: 'b # c' ");
+        assert_eq!(
+            parse_flow("[http://example.com, {a: b,},]", 1).unwrap(),
+            Node::Sequence(vec![
+                Node::Scalar("http://example.com".to_string()),
+                Node::Mapping(vec![("a".to_string(), Node::Scalar("b".to_string()))]),
+            ])
+        );
+    }
+
+    #[test]
+    fn errors_are_located_at_their_line() {
+        assert_eq!(
+            error("rules:\n  a: 1\n    b: 2"),
+            "line 3: unexpected indentation"
+        );
+        assert_eq!(
+            error("rules: [1,\n  2"),
+            "line 1: unterminated flow collection"
+        );
+        assert_eq!(error("a: ['b' c]"), "line 1: expected `,` between entries");
+        assert_eq!(error("a: {[b]: c}"), "line 1: keys must be scalars");
+        assert_eq!(error("a: {b}"), "line 1: expected `:` after a key");
+        assert_eq!(
+            error("a: 'b' c"),
+            "line 1: unexpected text after a quoted scalar"
+        );
+        assert_eq!(
+            error("a: [b] c"),
+            "line 1: unexpected text after a flow collection"
+        );
+        assert_eq!(error("a: b\nc"), "line 2: expected `key: value`");
+        for input in &["a: &anchor b", "a: *alias", "a: !tag b", "a: |", "a: [>]"] {
+            assert_eq!(
+                error(input),
+                "line 1: anchors, aliases, tags and multi-line scalars are not supported"
+            );
+        }
+    }
+}
@@ -0,0 +1,536 @@
+use super::{
+    commitlint,
+    toml::{self, Value},
+    Rule, Severity,
+};
+use crate::{grapheme, ParseOptions, DEFAULT_TYPES};
+use std::{collections::BTreeMap, error::Error, fmt, fs, io, path::Path, str::FromStr};
+
+/// The name of the configuration file that [`Config::from_toml`] is meant
+/// to read, committed at the root of a repository.
+pub const CONFIG_FILE_NAME: &str = ".conventional-commits.toml";
+
+/// The settings of the [`default_rules`](super::default_rules).
+///
+/// By default, the rules of the specification are checked, as parsed with
+/// [`Config::options`], only the [`DEFAULT_TYPES`] are allowed, the header
+/// is limited to 72 characters and the lines of the body to 100, and the
+/// description must not end with a full stop.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Config {
+    /// The options used to parse the message.
+    pub options: ParseOptions,
+    /// The allowed types. All types are allowed if this is `None`.
+    pub types: Option<Vec<String>>,
+    /// The allowed scopes. All scopes are allowed if this is `None`.
+    pub scopes: Option<Vec<String>>,
+    /// The maximum number of characters of the header.
+    pub header_max_length: Option<usize>,
+    /// Whether lengths are counted in graphemes, the characters a reader
+    /// perceives, instead of Unicode characters.
+    ///
+    /// Unicode characters are what most tools count, but they make a header
+    /// with combining accents or emoji sequences look longer than it is.
+    /// Without the `unicode-segmentation` feature, graphemes are
+    /// approximated and rare ones may be counted as several.
+    pub count_graphemes: bool,
+    /// The maximum number of characters of each line of the body.
+    ///
+    /// Lines with URLs and lines of code blocks are not limited, since they
+    /// cannot be wrapped.
+    pub body_max_line_length: Option<usize>,
+    /// The tokens of the footers that every commit has to have, like
+    /// `Signed-off-by`.
+    pub required_footers: Vec<String>,
+    /// The cases that the description has to be written in, or must not
+    /// be. Any case is allowed if this is `None`.
+    pub subject_case: Option<CaseRule>,
+    /// Whether the description must not end with a full stop.
+    pub subject_full_stop: bool,
+    /// The severities of rules by their [`Rule::id`], overriding
+    /// [`Rule::severity`].
+    pub severities: BTreeMap<String, Severity>,
+    /// The cases that the scopes have to be written in, or must not be. Any
+    /// case is allowed if this is `None`.
+    pub scope_case: Option<CaseRule>,
+}
+
+impl Default for Config {
+    /// Returns the default configuration, the specification with the
+    /// well-known types, a header of at most 72 characters, body lines of at
+    /// most 100 and no full stop.
+    fn default() -> Self {
+        Self {
+            options: ParseOptions::default(),
+            types: Some(DEFAULT_TYPES.iter().map(|ty| ty.to_string()).collect()),
+            scopes: None,
+            header_max_length: Some(72),
+            count_graphemes: false,
+            body_max_line_length: Some(100),
+            required_footers: Vec::new(),
+            subject_case: None,
+            subject_full_stop: true,
+            severities: BTreeMap::new(),
+            scope_case: None,
+        }
+    }
+}
+
+impl Config {
+    /// Returns the severity of `rule`, as overridden by
+    /// [`Config::severities`].
+    pub fn severity(&self, rule: &dyn Rule) -> Severity {
+        self.severities
+            .get(rule.id())
+            .copied()
+            .unwrap_or_else(|| rule.severity())
+    }
+
+    /// Returns the length of `text`, as counted with
+    /// [`Config::count_graphemes`].
+    pub fn length(&self, text: &str) -> usize {
+        if self.count_graphemes {
+            grapheme::count(text)
+        } else {
+            text.chars().count()
+        }
+    }
+
+    /// Returns the byte offset at which `text` gets longer than `max`, as
+    /// counted with [`Config::count_graphemes`].
+    pub(crate) fn overflow_start(&self, text: &str, max: usize) -> usize {
+        if self.count_graphemes {
+            grapheme::offset(text, max)
+        } else {
+            text.char_indices()
+                .nth(max)
+                .map_or(text.len(), |(idx, _)| idx)
+        }
+    }
+
+    /// Reads the configuration from a TOML file, like
+    /// [`CONFIG_FILE_NAME`].
+    ///
+    /// Only the subset of TOML that configuration files need is supported,
+    /// see [`Config::from_toml_str`] for it and for the keys.
+    pub fn from_toml(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let input = fs::read_to_string(path).map_err(ConfigError::Io)?;
+        Self::from_toml_str(&input)
+    }
+
+    /// Parses the configuration from TOML.
+    ///
+    /// The keys are named like the fields, with hyphens instead of
+    /// underscores. The `strict`, `autosquash` and `gitmoji` keys set the
+    /// [`Config::options`], and the types, scopes and lengths can be
+    /// disabled with `false`.
+    /// The cases are named as in commitlint, like `lower-case`: either one
+    /// case or an array of cases that are allowed, or an `always` or `never`
+    /// array in a table of the same name. The `severity` table sets the
+    /// [`Config::severities`] to `error`, `warning` or `off`. Unknown keys are
+    /// rejected, so that typos do not go unnoticed.
+    ///
+    /// Supported are comments, tables, bare and quoted keys, and values that
+    /// are basic or literal strings, integers, booleans or arrays of them,
+    /// which may span multiple lines. Dotted keys, inline tables, arrays of
+    /// tables, multi-line strings, floats and dates are rejected, as are keys
+    /// and tables that are defined twice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::lint::{Case, CaseRule, Config, Severity};
+    ///
+    /// let config = Config::from_toml_str(r#"
+    ///     types = ["feat", "fix", "docs"] # also checked in CI
+    ///     header-max-length = 72
+    ///     required-footers = ["Signed-off-by"]
+    ///     subject-case = "lower-case"
+    ///
+    ///     [scope-case]
+    ///     never = ["upper-case"]
+    ///
+    ///     [severity]
+    ///     header-max-length = "warning"
+    /// "#).unwrap();
+    /// assert_eq!(config.header_max_length, Some(72));
+    /// assert_eq!(config.subject_case, Some(CaseRule::Always(vec![Case::Lower])));
+    /// assert_eq!(config.scope_case, Some(CaseRule::Never(vec![Case::Upper])));
+    /// assert_eq!(config.severities["header-max-length"], Severity::Warning);
+    ///
+    /// let error = Config::from_toml_str("header-max-lenght = 72").unwrap_err();
+    /// assert_eq!(error.to_string(), "line 1: unknown key `header-max-lenght`");
+    ///
+    /// let error = Config::from_toml_str("strict = true\nstrict = false").unwrap_err();
+    /// assert_eq!(error.to_string(), "line 2: duplicate key `strict`");
+    ///
+    /// let error = Config::from_toml_str("types = 'feat").unwrap_err();
+    /// assert_eq!(error.to_string(), "line 1: unterminated string");
+    /// ```
+    pub fn from_toml_str(input: &str) -> Result<Self, ConfigError> {
+        let entries =
+            toml::parse(input).map_err(|(line, message)| ConfigError::Invalid { line, message })?;
+        let mut config = Self::default();
+        for entry in entries {
+            let line = entry.line;
+            config
+                .set(&entry.key, entry.value)
+                .map_err(|message| ConfigError::Invalid { line, message })?;
+        }
+        Ok(config)
+    }
+
+    /// Reads a commitlint configuration, like `.commitlintrc.json` or
+    /// `.commitlintrc.yml`, along with the names of the rules that are
+    /// ignored.
+    ///
+    /// See [`Config::from_commitlint_str`] for which rules are read.
+    pub fn from_commitlint(path: impl AsRef<Path>) -> Result<(Self, Vec<String>), ConfigError> {
+        let input = fs::read_to_string(path).map_err(ConfigError::Io)?;
+        Self::from_commitlint_str(&input)
+    }
+
+    /// Parses a commitlint configuration in its JSON or YAML form, along with
+    /// the names of the rules that are ignored.
+    ///
+    /// The rules `type-enum`, `scope-enum`, `header-max-length`,
+    /// `body-max-line-length`, `subject-case`, `scope-case` and
+    /// `trailer-exists` are read if they apply `always`, the case rules and
+    /// `subject-full-stop` if they apply `never`. They are disabled with
+    /// level `0` and reported as warnings with level `1`. Other rules that
+    /// are enabled, and rules whose value cannot be read, are ignored, and
+    /// their names are returned so that callers can warn about them. The
+    /// extended configurations are ignored except for
+    /// `@commitlint/config-conventional`, whose types, lengths and cases
+    /// are used.
+    ///
+    /// Of YAML, block mappings and sequences, flow collections, quoted and
+    /// plain scalars and comments are supported; anchors, aliases, tags and
+    /// multi-line scalars are rejected. JSON is read as YAML flow collections.
+    /// Configurations written in JavaScript, like `commitlint.config.js`,
+    /// cannot be read.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::lint::Config;
+    ///
+    /// let (config, ignored) = Config::from_commitlint_str(r#"
+    /// extends:
+    ///   - "@commitlint/config-conventional"
+    /// rules:
+    ///   header-max-length: [2, always, 72]
+    ///   scope-enum:
+    ///     - 2
+    ///     - always
+    ///     - [api, cli]
+    ///   body-leading-blank: [1, always]
+    ///   footer-leading-blank: [0, always]
+    /// "#).unwrap();
+    /// assert_eq!(config.header_max_length, Some(72));
+    /// assert_eq!(config.body_max_line_length, Some(100));
+    /// assert_eq!(config.scopes, Some(vec!["api".to_string(), "cli".to_string()]));
+    /// assert_eq!(ignored, ["body-leading-blank"]);
+    ///
+    /// let json = r#"{"rules": {"type-enum": [2, "always", ["feat", "fix"]]}}"#;
+    /// let (config, ignored) = Config::from_commitlint_str(json).unwrap();
+    /// assert_eq!(config.types, Some(vec!["feat".to_string(), "fix".to_string()]));
+    /// assert!(ignored.is_empty());
+    ///
+    /// let error = Config::from_commitlint_str("rules:\n  scope-enum: &scopes [2, always]");
+    /// assert!(error.is_err());
+    /// ```
+    pub fn from_commitlint_str(input: &str) -> Result<(Self, Vec<String>), ConfigError> {
+        commitlint::parse(input)
+    }
+
+    /// Sets the setting of a key of the configuration file.
+    fn set(&mut self, key: &str, value: Value) -> Result<(), String> {
+        match key {
+            "strict" => self.options.strict = boolean(key, value)?,
+            "autosquash" => self.options.autosquash = boolean(key, value)?,
+            "gitmoji" => self.options.gitmoji = boolean(key, value)?,
+            "types" => self.types = optional_strings(key, value)?,
+            "scopes" => self.scopes = optional_strings(key, value)?,
+            "header-max-length" => self.header_max_length = length(key, value)?,
+            "count-graphemes" => self.count_graphemes = boolean(key, value)?,
+            "body-max-line-length" => self.body_max_line_length = length(key, value)?,
+            "required-footers" => self.required_footers = strings(key, value)?,
+            "subject-case" => self.subject_case = case_rule(key, value, false)?,
+            "subject-case.always" => self.subject_case = case_rule(key, value, false)?,
+            "subject-case.never" => self.subject_case = case_rule(key, value, true)?,
+            "subject-full-stop" => self.subject_full_stop = boolean(key, value)?,
+            "scope-case" => self.scope_case = case_rule(key, value, false)?,
+            "scope-case.always" => self.scope_case = case_rule(key, value, false)?,
+            "scope-case.never" => self.scope_case = case_rule(key, value, true)?,
+            _ if key.starts_with("severity.") => {
+                let severity = match value {
+                    Value::String(s) => {
+                        s.parse().map_err(|_| format!("unknown severity `{}`", s))?
+                    }
+                    value => return Err(expected(key, "a string", &value)),
+                };
+                self.severities
+                    .insert(key["severity.".len()..].to_string(), severity);
+            }
+            _ => return Err(format!("unknown key `{}`", key)),
+        }
+        Ok(())
+    }
+}
+
+/// Returns the error message for a value of the wrong type.
+fn expected(key: &str, what: &str, value: &Value) -> String {
+    format!("`{}` must be {}, not {}", key, what, value.type_name())
+}
+
+fn boolean(key: &str, value: Value) -> Result<bool, String> {
+    match value {
+        Value::Boolean(b) => Ok(b),
+        value => Err(expected(key, "a boolean", &value)),
+    }
+}
+
+fn length(key: &str, value: Value) -> Result<Option<usize>, String> {
+    match value {
+        Value::Integer(n) if n >= 0 => Ok(Some(n as usize)),
+        Value::Boolean(false) => Ok(None),
+        value => Err(expected(key, "a positive integer or false", &value)),
+    }
+}
+
+fn strings(key: &str, value: Value) -> Result<Vec<String>, String> {
+    match value {
+        Value::Array(values) => values
+            .into_iter()
+            .map(|value| match value {
+                Value::String(s) => Ok(s),
+                value => Err(expected(key, "an array of strings", &value)),
+            })
+            .collect(),
+        value => Err(expected(key, "an array of strings", &value)),
+    }
+}
+
+fn optional_strings(key: &str, value: Value) -> Result<Option<Vec<String>>, String> {
+    match value {
+        Value::Boolean(false) => Ok(None),
+        value @ Value::Array(_) => strings(key, value).map(Some),
+        value => Err(expected(key, "an array of strings or false", &value)),
+    }
+}
+
+fn case_rule(key: &str, value: Value, never: bool) -> Result<Option<CaseRule>, String> {
+    let names = match value {
+        Value::Boolean(false) => return Ok(None),
+        Value::String(s) => vec![s],
+        value @ Value::Array(_) => strings(key, value)?,
+        value => return Err(expected(key, "a case, an array of cases or false", &value)),
+    };
+    let cases = names
+        .into_iter()
+        .map(|s| s.parse().map_err(|_| format!("unknown case `{}`", s)))
+        .collect::<Result<_, _>>()?;
+    Ok(Some(if never {
+        CaseRule::Never(cases)
+    } else {
+        CaseRule::Always(cases)
+    }))
+}
+
+/// A way to write text, named as in commitlint.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Case {
+    /// `lower-case`, without uppercase letters.
+    Lower,
+    /// `upper-case`, without lowercase letters.
+    Upper,
+    /// `sentence-case`, starting with an uppercase letter and without other
+    /// uppercase letters in any of the words, like `Add a parser`.
+    Sentence,
+    /// `start-case`, with words that start with uppercase letters.
+    Start,
+    /// `camel-case`, words joined with uppercase letters, like `apiClient`.
+    Camel,
+    /// `pascal-case`, words joined with uppercase letters, like `ApiClient`.
+    Pascal,
+    /// `kebab-case`, lowercase words separated by hyphens.
+    Kebab,
+    /// `snake-case`, lowercase words separated by underscores.
+    Snake,
+}
+
+impl Case {
+    /// Returns the name of the case, like `lower-case`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Case::Lower => "lower-case",
+            Case::Upper => "upper-case",
+            Case::Sentence => "sentence-case",
+            Case::Start => "start-case",
+            Case::Camel => "camel-case",
+            Case::Pascal => "pascal-case",
+            Case::Kebab => "kebab-case",
+            Case::Snake => "snake-case",
+        }
+    }
+
+    /// Returns `true` if `text` is written in the case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::lint::Case;
+    ///
+    /// assert!(Case::Lower.is(&"add a parser"));
+    /// assert!(Case::Sentence.is(&"Add a parser"));
+    /// assert!(!Case::Sentence.is(&"Add a Parser"));
+    /// assert!(Case::Start.is(&"Add A Parser"));
+    /// assert!(Case::Camel.is(&"apiClient"));
+    /// assert!(Case::Kebab.is(&"api-client"));
+    /// assert!(!Case::Kebab.is(&"api_client"));
+    /// ```
+    pub fn is(self, text: &str) -> bool {
+        match self {
+            Case::Lower => !text.chars().any(char::is_uppercase),
+            Case::Upper => !text.chars().any(char::is_lowercase),
+            Case::Sentence => is_capitalized(text),
+            Case::Start => text.split(' ').all(is_capitalized),
+            Case::Camel => is_joined(text) && !text.starts_with(char::is_uppercase),
+            Case::Pascal => is_joined(text) && !text.starts_with(char::is_lowercase),
+            Case::Kebab => is_separated(text, '-'),
+            Case::Snake => is_separated(text, '_'),
+        }
+    }
+}
+
+/// Returns `true` if `text` consists of lowercase words separated by
+/// single `separator`s.
+fn is_separated(text: &str, separator: char) -> bool {
+    text.split(separator).all(|word| {
+        !word.is_empty() && word.chars().all(|c| c.is_lowercase() || c.is_ascii_digit())
+    })
+}
+
+/// Returns `true` if `word`, or a whole sentence, starts with an uppercase
+/// letter and has no other uppercase letters.
+fn is_capitalized(word: &str) -> bool {
+    let mut chars = word.chars();
+    chars.next().map_or(true, |c| !c.is_lowercase()) && !chars.any(char::is_uppercase)
+}
+
+/// Returns `true` if `text` consists of letters and digits only.
+fn is_joined(text: &str) -> bool {
+    text.chars().all(char::is_alphanumeric)
+}
+
+impl fmt::Display for Case {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Case {
+    type Err = ();
+
+    /// Parses the name of a case, like `lower-case`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Case::Lower,
+            Case::Upper,
+            Case::Sentence,
+            Case::Start,
+            Case::Camel,
+            Case::Pascal,
+            Case::Kebab,
+            Case::Snake,
+        ]
+        .iter()
+        .copied()
+        .find(|case| case.as_str() == s)
+        .ok_or(())
+    }
+}
+
+/// A condition on the case of a text, as in the case rules of commitlint.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum CaseRule {
+    /// The text has to be written in one of the cases.
+    Always(Vec<Case>),
+    /// The text must not be written in any of the cases.
+    Never(Vec<Case>),
+}
+
+impl CaseRule {
+    /// Returns `true` if `text` satisfies the condition.
+    ///
+    /// Texts without letters satisfy every condition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::lint::{Case, CaseRule};
+    ///
+    /// let rule = CaseRule::Never(vec![Case::Sentence, Case::Upper]);
+    /// assert!(rule.matches("add a parser"));
+    /// assert!(!rule.matches("Add a parser"));
+    /// ```
+    pub fn matches(&self, text: &str) -> bool {
+        if !text.chars().any(char::is_alphabetic) {
+            return true;
+        }
+        match self {
+            CaseRule::Always(cases) => cases.iter().any(|case| case.is(text)),
+            CaseRule::Never(cases) => !cases.iter().any(|case| case.is(text)),
+        }
+    }
+
+    /// Returns the condition as a requirement on `what`, like `description
+    /// must be lower-case or sentence-case`.
+    pub(crate) fn requirement(&self, what: &str) -> String {
+        let (verb, cases) = match self {
+            CaseRule::Always(cases) => ("must be", cases),
+            CaseRule::Never(cases) => ("must not be", cases),
+        };
+        let mut requirement = format!("{} {} ", what, verb);
+        for (idx, case) in cases.iter().enumerate() {
+            if idx > 0 {
+                requirement.push_str(if idx + 1 == cases.len() { " or " } else { ", " });
+            }
+            requirement.push_str(case.as_str());
+        }
+        requirement
+    }
+}
+
+/// An error returned when a configuration could not be read.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The file could not be read.
+    Io(io::Error),
+    /// The configuration is not valid.
+    Invalid {
+        /// The line of the problem, starting at 1.
+        line: usize,
+        /// The description of the problem.
+        message: String,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(error) => write!(f, "{}", error),
+            ConfigError::Invalid { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConfigError::Io(error) => Some(error),
+            ConfigError::Invalid { .. } => None,
+        }
+    }
+}
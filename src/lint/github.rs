@@ -0,0 +1,62 @@
+//! Workflow commands that show lint results as annotations in GitHub
+//! Actions.
+
+use super::{LintReport, Severity};
+use std::fmt;
+
+/// A lint report that is displayed as GitHub Actions workflow commands, one
+/// `::error` or `::warning` line per violation.
+///
+/// Printed to the output of a step, the violations show up as annotations
+/// of the file the message was read from. Created by
+/// [`LintReport::github_annotations`].
+#[derive(Copy, Clone, Debug)]
+pub struct GithubAnnotations<'r> {
+    report: &'r LintReport,
+    file: &'r str,
+}
+
+impl<'r> GithubAnnotations<'r> {
+    pub(crate) fn new(report: &'r LintReport, file: &'r str) -> Self {
+        Self { report, file }
+    }
+}
+
+impl fmt::Display for GithubAnnotations<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for violation in &self.report.violations {
+            let command = match violation.severity {
+                Severity::Error => "error",
+                Severity::Warning | Severity::Off => "warning",
+            };
+            write!(f, "::{} file=", command)?;
+            write_escaped(f, self.file, true)?;
+            write!(
+                f,
+                ",line={},col={},title=",
+                violation.line, violation.column
+            )?;
+            write_escaped(f, violation.rule, true)?;
+            f.write_str("::")?;
+            write_escaped(f, &violation.message, false)?;
+            f.write_str("\n")?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes `s` escaped as the value of a property or, if `property` is
+/// `false`, as the message of a workflow command.
+fn write_escaped(f: &mut fmt::Formatter<'_>, s: &str, property: bool) -> fmt::Result {
+    for c in s.chars() {
+        match c {
+            '%' => f.write_str("%25")?,
+            '\r' => f.write_str("%0D")?,
+            '\n' => f.write_str("%0A")?,
+            ':' if property => f.write_str("%3A")?,
+            ',' if property => f.write_str("%2C")?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,92 @@
+//! The rule that [`default_rules`](super::default_rules) adds with the
+//! `imperative` feature.
+
+use super::{Fix, LintContext, Rule, Severity, Violation};
+use crate::Span;
+
+/// The verbs, one per line with the imperative form first.
+const WORDS: &str = include_str!("imperative.txt");
+
+/// Returns the imperative form of `word` if it is a past tense, participle or
+/// gerund in the word list.
+fn imperative(word: &str) -> Option<&'static str> {
+    WORDS
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .find_map(|line| {
+            let mut forms = line.split(' ');
+            let imperative = forms.next()?;
+            if forms.any(|form| form.eq_ignore_ascii_case(word)) {
+                Some(imperative)
+            } else {
+                None
+            }
+        })
+}
+
+/// Reports descriptions that start with a past tense or a gerund, like
+/// `added` or `adding`, instead of the imperative mood.
+///
+/// It is a heuristic based on a list of verbs that are common in commit
+/// messages, so its violations are warnings. They come with a suggestion to
+/// use the imperative form.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::lint::{self, Config, Severity};
+///
+/// let message = "feat: Added a parser";
+/// let report = lint::lint(message, &Config::default());
+/// let violation = &report.violations()[0];
+/// assert_eq!(violation.message(), "use the imperative mood: `Add` instead of `Added`");
+/// assert_eq!(violation.severity(), Severity::Warning);
+/// assert_eq!(violation.fix().unwrap().apply(message), "feat: Add a parser");
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ImperativeMood;
+
+impl Rule for ImperativeMood {
+    fn id(&self) -> &'static str {
+        "subject-imperative"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, context: &LintContext<'_>, violations: &mut Vec<Violation>) {
+        let desc = context.spans.desc;
+        let text = context.text(desc);
+        let len = text
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(text.len());
+        let word = &text[..len];
+        let imperative = match imperative(word) {
+            Some(imperative) => imperative,
+            None => return,
+        };
+        let replacement = if word.starts_with(char::is_uppercase) {
+            let mut chars = imperative.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        } else {
+            imperative.to_string()
+        };
+        let span = Span::new(desc.start, desc.start + len);
+        violations.push(
+            context
+                .violation(
+                    self.id(),
+                    format!(
+                        "use the imperative mood: `{}` instead of `{}`",
+                        replacement, word
+                    ),
+                    span,
+                )
+                .with_fix(Fix::suggestion(span, replacement)),
+        );
+    }
+}
@@ -0,0 +1,569 @@
+//! The rules that [`default_rules`](super::default_rules) returns.
+
+use super::{suggest, Fix, LintContext, Rule, Severity, Violation};
+use crate::{parser, ParseError, ParseErrorKind, Span};
+
+/// Reports where the message does not follow the specification, as parsed
+/// with [`Config::options`](super::Config::options).
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Syntax;
+
+impl Rule for Syntax {
+    fn id(&self) -> &'static str {
+        "syntax"
+    }
+
+    fn check(&self, context: &LintContext<'_>, violations: &mut Vec<Violation>) {
+        for error in context.errors {
+            let violation = context.violation(self.id(), error.kind().to_string(), error.span());
+            violations.push(match syntax_fix(context.input, error) {
+                Some(fix) => violation.with_fix(fix),
+                None => violation,
+            });
+        }
+    }
+}
+
+/// Returns the fix of a parse error, for the errors that can be fixed
+/// without changing the meaning of the message.
+fn syntax_fix(input: &str, error: &ParseError) -> Option<Fix> {
+    let span = error.span();
+    match error.kind() {
+        ParseErrorKind::InvalidType => {
+            // Fix types that are only spaced or cased wrongly, like `Feat `.
+            let ty = &input[span.start..span.end];
+            let fixed = ty.trim().to_lowercase();
+            if fixed == ty || fixed.is_empty() || !fixed.chars().all(char::is_alphanumeric) {
+                return None;
+            }
+            Some(Fix::new(span, fixed))
+        }
+        ParseErrorKind::MissingSeparator => {
+            // Only fix separators that are there but spaced wrongly.
+            let is_blank = |c: char| c == ' ' || c == '\t';
+            let start = input[..span.start].trim_end_matches(is_blank).len();
+            let rest = input[span.start..].trim_start_matches(is_blank);
+            if !rest.starts_with(':') {
+                return None;
+            }
+            let end = input.len() - rest[1..].trim_start_matches(is_blank).len();
+            Some(Fix::new(Span::new(start, end), ": "))
+        }
+        ParseErrorKind::UnexpectedWhitespace if !input[span.start..span.end].contains('\n') => {
+            Some(Fix::new(span, ""))
+        }
+        ParseErrorKind::InvalidFooterToken => {
+            let token = &input[span.start..span.end];
+            Some(Fix::new(
+                span,
+                token.split_whitespace().collect::<Vec<_>>().join("-"),
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Reports the [`Config::required_footers`](super::Config::required_footers)
+/// that the message does not have.
+///
+/// Tokens are compared as the specification does, see
+/// [`Footer::has_token`](crate::Footer::has_token).
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::lint::{self, Config};
+///
+/// let config = Config {
+///     required_footers: vec!["Signed-off-by".to_string()],
+///     ..Config::default()
+/// };
+/// let report = lint::lint("feat: add a parser", &config);
+/// assert_eq!(report.violations()[0].message(), "missing `Signed-off-by` footer");
+/// assert!(lint::lint("feat: add a parser\n\nsigned-off-by: Jane <jane@example.com>", &config).is_ok());
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct RequiredFooters;
+
+impl Rule for RequiredFooters {
+    fn id(&self) -> &'static str {
+        "trailer-exists"
+    }
+
+    fn check(&self, context: &LintContext<'_>, violations: &mut Vec<Violation>) {
+        let end = context.input.trim_end().len();
+        for token in &context.config.required_footers {
+            if context.commit.footer(token).is_none() {
+                violations.push(context.violation(
+                    self.id(),
+                    format!("missing `{}` footer", token),
+                    Span::new(end, end),
+                ));
+            }
+        }
+    }
+}
+
+/// Reports headers longer than
+/// [`Config::header_max_length`](super::Config::header_max_length).
+///
+/// The violation covers the part of the header beyond the limit.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::lint::{self, Config};
+///
+/// let header = format!("feat: {}", "a".repeat(70));
+/// let report = lint::lint(&header, &Config::default());
+/// assert_eq!(
+///     report.violations()[0].message(),
+///     "header is 76 characters long, 4 more than the maximum of 72"
+/// );
+/// assert_eq!(report.violations()[0].column(), 73);
+///
+/// let header = format!("feat: {}", "é".repeat(66));
+/// assert!(lint::lint(&header, &Config::default()).is_ok());
+///
+/// let config = Config {
+///     count_graphemes: true,
+///     ..Config::default()
+/// };
+/// let header = format!("feat: {}", "e\u{301}".repeat(66));
+/// assert!(lint::lint(&header, &config).is_ok());
+/// assert!(!lint::lint(&header, &Config::default()).is_ok());
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct HeaderMaxLength;
+
+impl Rule for HeaderMaxLength {
+    fn id(&self) -> &'static str {
+        "header-max-length"
+    }
+
+    fn check(&self, context: &LintContext<'_>, violations: &mut Vec<Violation>) {
+        let config = context.config;
+        let max = match config.header_max_length {
+            Some(max) => max,
+            None => return,
+        };
+        let header = context.text(context.spans.header);
+        let length = config.length(header);
+        if length > max {
+            let start = context.spans.header.start + config.overflow_start(header, max);
+            violations.push(context.violation(
+                self.id(),
+                format!(
+                    "header is {} characters long, {} more than the maximum of {}",
+                    length,
+                    length - max,
+                    max
+                ),
+                Span::new(start, context.spans.header.end),
+            ));
+        }
+    }
+}
+
+/// Reports lines of the body longer than
+/// [`Config::body_max_line_length`](super::Config::body_max_line_length).
+///
+/// Lines that contain a URL and the lines of fenced or indented code blocks
+/// are skipped, since wrapping them would break them.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::lint::{self, Config};
+///
+/// let config = Config {
+///     body_max_line_length: Some(20),
+///     ..Config::default()
+/// };
+/// let message = "fix: wrap the body\n\nThis line is too long to fit.\n\
+///     See https://example.com/a/very/long/path\n\n    let code = \"too long to wrap\";";
+/// let report = lint::lint(message, &config);
+/// assert_eq!(report.violations().len(), 1);
+/// assert_eq!(report.violations()[0].line(), 3);
+/// assert_eq!(
+///     report.violations()[0].message(),
+///     "body line is 29 characters long, 9 more than the maximum of 20"
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BodyMaxLineLength;
+
+impl Rule for BodyMaxLineLength {
+    fn id(&self) -> &'static str {
+        "body-max-line-length"
+    }
+
+    fn check(&self, context: &LintContext<'_>, violations: &mut Vec<Violation>) {
+        let config = context.config;
+        let (max, body) = match (config.body_max_line_length, context.spans.body) {
+            (Some(max), Some(body)) => (max, body),
+            _ => return,
+        };
+        let mut in_fence = false;
+        let mut start = body.start;
+        for line in context.text(body).split('\n') {
+            let line_start = start;
+            start += line.len() + 1;
+            let line = line.trim_end_matches('\r');
+            if line.trim_start().starts_with("```") || line.trim_start().starts_with("~~~") {
+                in_fence = !in_fence;
+                continue;
+            }
+            let is_code = line.starts_with("    ") || line.starts_with('\t');
+            if in_fence || is_code || line.split_whitespace().any(is_url) {
+                continue;
+            }
+            let length = config.length(line);
+            if length > max {
+                violations.push(context.violation(
+                    self.id(),
+                    format!(
+                        "body line is {} characters long, {} more than the maximum of {}",
+                        length,
+                        length - max,
+                        max
+                    ),
+                    Span::new(
+                        line_start + config.overflow_start(line, max),
+                        line_start + line.len(),
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+/// Returns `true` if `word` contains a URL, like `<https://example.com>`.
+fn is_url(word: &str) -> bool {
+    word.find("://").map_or(false, |idx| {
+        let scheme = word[..idx].trim_start_matches(|c: char| !c.is_ascii_alphabetic());
+        !scheme.is_empty()
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.')
+    })
+}
+
+/// Reports descriptions that do not satisfy
+/// [`Config::subject_case`](super::Config::subject_case).
+///
+/// If changing the case of the first letter satisfies it, the violation
+/// comes with a fix that does.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::lint::{self, Case, CaseRule, Config};
+///
+/// let config = Config {
+///     subject_case: Some(CaseRule::Always(vec![Case::Lower])),
+///     ..Config::default()
+/// };
+/// let report = lint::lint("feat: Add a parser", &config);
+/// assert_eq!(report.violations()[0].message(), "description must be lower-case");
+/// assert!(lint::lint("feat: add a parser", &config).is_ok());
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct SubjectCase;
+
+impl Rule for SubjectCase {
+    fn id(&self) -> &'static str {
+        "subject-case"
+    }
+
+    fn check(&self, context: &LintContext<'_>, violations: &mut Vec<Violation>) {
+        let rule = match &context.config.subject_case {
+            Some(rule) => rule,
+            None => return,
+        };
+        let desc = context.spans.desc;
+        let text = context.text(desc);
+        if rule.matches(text) {
+            return;
+        }
+        let violation = context.violation(self.id(), rule.requirement("description"), desc);
+        let first = match text.chars().next() {
+            Some(first) => first,
+            None => return violations.push(violation),
+        };
+        let toggled: String = if first.is_uppercase() {
+            first.to_lowercase().collect()
+        } else {
+            first.to_uppercase().collect()
+        };
+        let fixed = format!("{}{}", toggled, &text[first.len_utf8()..]);
+        violations.push(if rule.matches(&fixed) {
+            violation.with_fix(Fix::new(
+                Span::new(desc.start, desc.start + first.len_utf8()),
+                toggled,
+            ))
+        } else {
+            violation
+        });
+    }
+}
+
+/// Reports descriptions that end with a full stop, if
+/// [`Config::subject_full_stop`](super::Config::subject_full_stop) is set.
+///
+/// The violation comes with a fix that removes the full stop.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::lint::{self, Config};
+///
+/// let message = "feat: add a parser.";
+/// let report = lint::lint(message, &Config::default());
+/// let violation = &report.violations()[0];
+/// assert_eq!(violation.message(), "description must not end with a full stop");
+/// assert_eq!(violation.fix().unwrap().apply(message), "feat: add a parser");
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct SubjectFullStop;
+
+impl Rule for SubjectFullStop {
+    fn id(&self) -> &'static str {
+        "subject-full-stop"
+    }
+
+    fn check(&self, context: &LintContext<'_>, violations: &mut Vec<Violation>) {
+        if !context.config.subject_full_stop {
+            return;
+        }
+        let desc = context.spans.desc;
+        let text = context.text(desc);
+        let trimmed = text.trim_end_matches('.');
+        if trimmed.len() < text.len() {
+            let span = Span::new(desc.start + trimmed.len(), desc.end);
+            violations.push(
+                context
+                    .violation(self.id(), "description must not end with a full stop", span)
+                    .with_fix(Fix::new(span, "")),
+            );
+        }
+    }
+}
+
+/// Reports types that are not in [`Config::types`](super::Config::types),
+/// listing the allowed ones.
+///
+/// If the type looks like a misspelling of an allowed one, that one is
+/// suggested.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::lint::{self, Config};
+///
+/// let report = lint::lint("release: bump the version", &Config::default());
+/// assert_eq!(
+///     report.violations()[0].message(),
+///     "type `release` is not allowed, expected one of feat, fix, perf, refactor, revert, \
+///      docs, style, test, build, ci, chore"
+/// );
+///
+/// let message = "fet: add a parser";
+/// let report = lint::lint(message, &Config::default());
+/// let violation = &report.violations()[0];
+/// assert!(violation.message().starts_with("type `fet` is not allowed, did you mean `feat`?"));
+/// assert_eq!(violation.fix().unwrap().apply(message), "feat: add a parser");
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct TypeEnum;
+
+impl Rule for TypeEnum {
+    fn id(&self) -> &'static str {
+        "type-enum"
+    }
+
+    fn check(&self, context: &LintContext<'_>, violations: &mut Vec<Violation>) {
+        let ty = context.commit.ty;
+        let types = match &context.config.types {
+            Some(types) if !ty.is_empty() => types,
+            _ => return,
+        };
+        if types.iter().any(|allowed| allowed == ty) {
+            return;
+        }
+        let span = context.spans.ty;
+        let message = format!("type `{}` is not allowed", ty);
+        let expected = format!("expected one of {}", types.join(", "));
+        violations.push(
+            match suggest::suggest(ty, types.iter().map(String::as_str)) {
+                Some(suggestion) => context
+                    .violation(
+                        self.id(),
+                        format!("{}, did you mean `{}`? {}", message, suggestion, expected),
+                        span,
+                    )
+                    .with_fix(Fix::suggestion(span, suggestion)),
+                None => context.violation(self.id(), format!("{}, {}", message, expected), span),
+            },
+        );
+    }
+}
+
+/// Returns the individual scopes of the message with their spans.
+fn scopes<'a>(context: &LintContext<'a>) -> impl Iterator<Item = (&'a str, Span)> {
+    let input = context.input;
+    let scope = context.spans.scope.map(|span| context.text(span));
+    parser::split_scopes(scope).map(move |scope| (scope, Span::of(input, scope)))
+}
+
+/// Reports scopes that are not in [`Config::scopes`](super::Config::scopes),
+/// listing the allowed ones.
+///
+/// Each of multiple scopes, like `api` and `cli` in `feat(api,cli)`, has to
+/// be allowed.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::lint::{self, Config};
+///
+/// let config = Config {
+///     scopes: Some(vec!["api".to_string(), "cli".to_string()]),
+///     ..Config::default()
+/// };
+/// let report = lint::lint("feat(api,web): add a route", &config);
+/// assert_eq!(
+///     report.violations()[0].message(),
+///     "scope `web` is not allowed, expected one of api, cli"
+/// );
+/// assert_eq!(report.violations()[0].column(), 10);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ScopeEnum;
+
+impl Rule for ScopeEnum {
+    fn id(&self) -> &'static str {
+        "scope-enum"
+    }
+
+    fn check(&self, context: &LintContext<'_>, violations: &mut Vec<Violation>) {
+        let allowed = match &context.config.scopes {
+            Some(allowed) => allowed,
+            None => return,
+        };
+        for (scope, span) in scopes(context) {
+            if !allowed.iter().any(|allowed| allowed == scope) {
+                violations.push(context.violation(
+                    self.id(),
+                    format!(
+                        "scope `{}` is not allowed, expected one of {}",
+                        scope,
+                        allowed.join(", ")
+                    ),
+                    span,
+                ));
+            }
+        }
+    }
+}
+
+/// Reports scopes that do not satisfy
+/// [`Config::scope_case`](super::Config::scope_case).
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::lint::{self, Case, CaseRule, Config};
+///
+/// let config = Config {
+///     scope_case: Some(CaseRule::Always(vec![Case::Kebab])),
+///     ..Config::default()
+/// };
+/// let report = lint::lint("feat(apiClient): add retries", &config);
+/// assert_eq!(report.violations()[0].message(), "scope `apiClient` must be kebab-case");
+/// assert!(lint::lint("feat(api-client): add retries", &config).is_ok());
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ScopeCase;
+
+impl Rule for ScopeCase {
+    fn id(&self) -> &'static str {
+        "scope-case"
+    }
+
+    fn check(&self, context: &LintContext<'_>, violations: &mut Vec<Violation>) {
+        let rule = match &context.config.scope_case {
+            Some(rule) => rule,
+            None => return,
+        };
+        for (scope, span) in scopes(context) {
+            if !rule.matches(scope) {
+                violations.push(context.violation(
+                    self.id(),
+                    rule.requirement(&format!("scope `{}`", scope)),
+                    span,
+                ));
+            }
+        }
+    }
+}
+
+/// Reports footer tokens that look like misspellings of well-known tokens or
+/// of the [`Config::required_footers`](super::Config::required_footers), like
+/// `Signd-off-by`.
+///
+/// Since it is a guess, its violations are warnings, and they come with a
+/// suggestion of the intended token.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::lint::{self, Config};
+///
+/// let message = "fix: handle empty input\n\nSignd-off-by: Jane <jane@example.com>";
+/// let report = lint::lint(message, &Config::default());
+/// let violation = &report.violations()[0];
+/// assert_eq!(
+///     violation.message(),
+///     "footer token `Signd-off-by` looks misspelled, did you mean `Signed-off-by`?"
+/// );
+/// assert!(violation.fix().unwrap().apply(message).ends_with("\n\nSigned-off-by: Jane <jane@example.com>"));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FooterTokenSpelling;
+
+impl Rule for FooterTokenSpelling {
+    fn id(&self) -> &'static str {
+        "trailer-spelling"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, context: &LintContext<'_>, violations: &mut Vec<Violation>) {
+        let known = || {
+            suggest::KNOWN_TOKENS
+                .iter()
+                .copied()
+                .chain(context.config.required_footers.iter().map(String::as_str))
+        };
+        for footer in &context.spans.footer {
+            let token = context.text(footer.token);
+            if let Some(suggestion) = suggest::suggest(token, known()) {
+                violations.push(
+                    context
+                        .violation(
+                            self.id(),
+                            format!(
+                                "footer token `{}` looks misspelled, did you mean `{}`?",
+                                token, suggestion
+                            ),
+                            footer.token,
+                        )
+                        .with_fix(Fix::suggestion(footer.token, suggestion)),
+                );
+            }
+        }
+    }
+}
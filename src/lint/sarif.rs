@@ -0,0 +1,110 @@
+//! The SARIF 2.1.0 form of lint reports, as read by GitHub code scanning.
+
+use super::{LintReport, Severity, Violation};
+use std::fmt::{self, Write};
+
+/// The project page, linked from the tool of the log.
+const INFORMATION_URI: &str =
+    "https://github.com/conventional-commits-rs/conventional-commits-types";
+
+/// Writes `report` as a SARIF log with a single run, whose results are
+/// located in the artifact at `uri`.
+pub(crate) fn write_sarif<W: Write>(w: &mut W, report: &LintReport, uri: &str) -> fmt::Result {
+    let mut rules: Vec<&str> = Vec::new();
+    for violation in &report.violations {
+        if !rules.contains(&violation.rule) {
+            rules.push(violation.rule);
+        }
+    }
+
+    w.write_str(
+        r#"{"version":"2.1.0","$schema":"https://json.schemastore.org/sarif-2.1.0.json","#,
+    )?;
+    w.write_str(r#""runs":[{"tool":{"driver":{"name":"#)?;
+    write_string(w, env!("CARGO_PKG_NAME"))?;
+    w.write_str(r#","version":"#)?;
+    write_string(w, env!("CARGO_PKG_VERSION"))?;
+    w.write_str(r#","informationUri":"#)?;
+    write_string(w, INFORMATION_URI)?;
+    w.write_str(r#","rules":["#)?;
+    for (idx, rule) in rules.iter().enumerate() {
+        if idx > 0 {
+            w.write_char(',')?;
+        }
+        w.write_str(r#"{"id":"#)?;
+        write_string(w, rule)?;
+        w.write_char('}')?;
+    }
+    w.write_str(r#"]}},"columnKind":"unicodeCodePoints","results":["#)?;
+    for (idx, violation) in report.violations.iter().enumerate() {
+        if idx > 0 {
+            w.write_char(',')?;
+        }
+        let rule_index = rules
+            .iter()
+            .position(|rule| *rule == violation.rule)
+            .unwrap_or_default();
+        write_result(w, violation, rule_index, uri)?;
+    }
+    w.write_str("]}]}")
+}
+
+fn write_result<W: Write>(
+    w: &mut W,
+    violation: &Violation,
+    rule_index: usize,
+    uri: &str,
+) -> fmt::Result {
+    let level = match violation.severity {
+        Severity::Error => "error",
+        Severity::Warning | Severity::Off => "warning",
+    };
+    w.write_str(r#"{"ruleId":"#)?;
+    write_string(w, violation.rule)?;
+    write!(
+        w,
+        r#","ruleIndex":{},"level":"{}","message":{{"text":"#,
+        rule_index, level
+    )?;
+    write_string(w, &violation.message)?;
+    w.write_str(r#"},"locations":[{"physicalLocation":{"artifactLocation":{"uri":"#)?;
+    write_string(w, uri)?;
+    write!(
+        w,
+        r#"}},"region":{{"startLine":{},"startColumn":{},"byteOffset":{},"byteLength":{}}}}}}}]"#,
+        violation.line,
+        violation.column,
+        violation.span.start,
+        violation.span.len()
+    )?;
+    if let Some(fix) = &violation.fix {
+        w.write_str(r#","fixes":[{"artifactChanges":[{"artifactLocation":{"uri":"#)?;
+        write_string(w, uri)?;
+        write!(
+            w,
+            r#"}},"replacements":[{{"deletedRegion":{{"byteOffset":{},"byteLength":{}}},"insertedContent":{{"text":"#,
+            fix.span.start,
+            fix.span.len()
+        )?;
+        write_string(w, &fix.replacement)?;
+        w.write_str("}}]}]}]")?;
+    }
+    w.write_char('}')
+}
+
+/// Writes `s` as a JSON string.
+fn write_string<W: Write>(w: &mut W, s: &str) -> fmt::Result {
+    w.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => w.write_str("\\\"")?,
+            '\\' => w.write_str("\\\\")?,
+            '\n' => w.write_str("\\n")?,
+            '\r' => w.write_str("\\r")?,
+            '\t' => w.write_str("\\t")?,
+            c if c.is_control() => write!(w, "\\u{:04x}", c as u32)?,
+            c => w.write_char(c)?,
+        }
+    }
+    w.write_char('"')
+}
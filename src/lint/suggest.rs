@@ -0,0 +1,86 @@
+//! Suggestions for misspelled types and footer tokens.
+
+use crate::{
+    ACKED_BY_TOKEN, BREAKING_CHANGE_TOKEN, BREAKING_CHANGE_TOKEN_ALIAS, CLOSES_TOKEN,
+    CO_AUTHORED_BY_TOKEN, FIXES_TOKEN, REFS_TOKEN, REVIEWED_BY_TOKEN, SIGNED_OFF_BY_TOKEN,
+};
+
+/// The footer tokens that misspelled tokens are compared with, in addition to
+/// the required ones.
+pub(crate) const KNOWN_TOKENS: &[&str] = &[
+    BREAKING_CHANGE_TOKEN,
+    BREAKING_CHANGE_TOKEN_ALIAS,
+    SIGNED_OFF_BY_TOKEN,
+    CO_AUTHORED_BY_TOKEN,
+    REVIEWED_BY_TOKEN,
+    ACKED_BY_TOKEN,
+    REFS_TOKEN,
+    CLOSES_TOKEN,
+    FIXES_TOKEN,
+];
+
+/// Returns the number of characters that have to be inserted, removed or
+/// replaced to turn `a` into `b`, ignoring case.
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().flat_map(char::to_lowercase).collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().flat_map(char::to_lowercase).enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + if ca == cb { 0 } else { 1 };
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Returns the distance between `text` and `candidate` if `text` could be a
+/// misspelling of it.
+///
+/// The words separated by hyphens or spaces are compared one by one, and each
+/// may differ in at most a third of its characters, so that `Reviewed-on` is
+/// not taken for a misspelling of `Reviewed-by`.
+fn misspelling(text: &str, candidate: &str) -> Option<usize> {
+    let (words, candidate_words) = (words(text), words(candidate));
+    if words.len() != candidate_words.len() {
+        return None;
+    }
+    words
+        .iter()
+        .zip(&candidate_words)
+        .try_fold(0, |total, (word, candidate)| {
+            let distance = distance(word, candidate);
+            let max = (candidate.chars().count() / 3).max(1);
+            if distance <= max {
+                Some(total + distance)
+            } else {
+                None
+            }
+        })
+}
+
+fn words(text: &str) -> Vec<&str> {
+    text.split(|c| c == '-' || c == ' ').collect()
+}
+
+/// Returns the candidate that `text` is most likely a misspelling of.
+///
+/// Texts that equal a candidate apart from case are not misspellings.
+pub(crate) fn suggest<'a>(
+    text: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let mut best: Option<(usize, &str)> = None;
+    for candidate in candidates {
+        match misspelling(text, candidate) {
+            Some(0) => return None,
+            Some(distance) if best.map_or(true, |(best, _)| distance < best) => {
+                best = Some((distance, candidate))
+            }
+            _ => {}
+        }
+    }
+    best.map(|(_, candidate)| candidate)
+}
@@ -0,0 +1,378 @@
+//! A parser for the subset of TOML that configuration files use.
+//!
+//! Supported are comments, tables, bare and quoted keys, and values that
+//! are basic or literal strings, integers, booleans or arrays of them, which
+//! may span multiple lines. Dotted keys, inline tables, dates and floats are
+//! not. As in TOML, keys and tables must not be defined twice.
+
+use std::{collections::HashSet, iter::Peekable, str::Chars};
+
+/// A value of a key.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Value {
+    String(String),
+    Integer(i64),
+    Boolean(bool),
+    Array(Vec<Value>),
+}
+
+impl Value {
+    /// Returns the name of the type of the value, for error messages.
+    pub(crate) fn type_name(&self) -> &'static str {
+        match self {
+            Value::String(_) => "a string",
+            Value::Integer(_) => "an integer",
+            Value::Boolean(_) => "a boolean",
+            Value::Array(_) => "an array",
+        }
+    }
+}
+
+/// A key with its value and the line it is defined on, starting at 1.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Entry {
+    pub(crate) line: usize,
+    /// The key, prefixed with the name of its table and a dot.
+    pub(crate) key: String,
+    pub(crate) value: Value,
+}
+
+/// Parses a document into its keys, in order.
+///
+/// Fails with the line and a description of the first problem.
+pub(crate) fn parse(input: &str) -> Result<Vec<Entry>, (usize, String)> {
+    let mut entries = Vec::new();
+    let mut tables = HashSet::new();
+    let mut keys = HashSet::new();
+    let mut table = String::new();
+    let mut lines = input.lines().enumerate();
+    while let Some((idx, line)) = lines.next() {
+        let line_number = idx + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with('[') {
+            let name = strip_comment(line).trim();
+            if !name.ends_with(']') || name.starts_with("[[") {
+                return Err((line_number, "invalid table header".to_string()));
+            }
+            table = parse_key(name[1..name.len() - 1].trim())
+                .ok_or_else(|| (line_number, "invalid table name".to_string()))?;
+            if !tables.insert(table.clone()) {
+                return Err((line_number, format!("duplicate table `{}`", table)));
+            }
+            continue;
+        }
+
+        let eq = line
+            .find('=')
+            .ok_or_else(|| (line_number, "expected `key = value`".to_string()))?;
+        let key =
+            parse_key(line[..eq].trim()).ok_or_else(|| (line_number, "invalid key".to_string()))?;
+        let mut text = line[eq + 1..].to_string();
+        // Arrays may continue on the following lines.
+        while !is_complete(&text) {
+            match lines.next() {
+                Some((_, next)) => {
+                    text.push('\n');
+                    text.push_str(next);
+                }
+                None => return Err((line_number, "unterminated array".to_string())),
+            }
+        }
+
+        let mut chars = text.chars().peekable();
+        let value = parse_value(&mut chars).map_err(|message| (line_number, message))?;
+        skip_whitespace(&mut chars);
+        if chars.peek().is_some() {
+            return Err((line_number, "unexpected text after the value".to_string()));
+        }
+        let key = if table.is_empty() {
+            key
+        } else {
+            format!("{}.{}", table, key)
+        };
+        if !keys.insert(key.clone()) {
+            return Err((line_number, format!("duplicate key `{}`", key)));
+        }
+        entries.push(Entry {
+            line: line_number,
+            key,
+            value,
+        });
+    }
+    Ok(entries)
+}
+
+/// Parses a bare or quoted key.
+fn parse_key(key: &str) -> Option<String> {
+    if key.len() >= 2 && key.starts_with('"') && key.ends_with('"') {
+        return Some(key[1..key.len() - 1].to_string());
+    }
+    if !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        Some(key.to_string())
+    } else {
+        None
+    }
+}
+
+/// Removes a comment from a line without strings.
+fn strip_comment(line: &str) -> &str {
+    line.find('#').map_or(line, |idx| &line[..idx])
+}
+
+/// Returns `true` if all brackets outside of strings and comments are
+/// closed.
+fn is_complete(text: &str) -> bool {
+    let mut depth = 0i32;
+    let mut quote = None;
+    let mut escaped = false;
+    let mut comment = false;
+    for c in text.chars() {
+        if comment {
+            comment = c != '\n';
+            continue;
+        }
+        match quote {
+            Some(q) => {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' && q == '"' {
+                    escaped = true;
+                } else if c == q {
+                    quote = None;
+                }
+            }
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '[' => depth += 1,
+                ']' => depth -= 1,
+                '#' => comment = true,
+                _ => {}
+            },
+        }
+    }
+    depth <= 0 || quote.is_some()
+}
+
+/// Skips whitespace, line breaks and comments.
+fn skip_whitespace(chars: &mut Peekable<Chars<'_>>) {
+    while let Some(&c) = chars.peek() {
+        if c == '#' {
+            while chars.next().map_or(false, |c| c != '\n') {}
+        } else if c.is_whitespace() {
+            chars.next();
+        } else {
+            break;
+        }
+    }
+}
+
+/// Parses a value.
+fn parse_value(chars: &mut Peekable<Chars<'_>>) -> Result<Value, String> {
+    skip_whitespace(chars);
+    match chars.peek() {
+        Some('"') => {
+            chars.next();
+            parse_basic_string(chars).map(Value::String)
+        }
+        Some('\'') => {
+            chars.next();
+            let mut string = String::new();
+            loop {
+                match chars.next() {
+                    Some('\'') => return Ok(Value::String(string)),
+                    Some('\n') | None => return Err("unterminated string".to_string()),
+                    Some(c) => string.push(c),
+                }
+            }
+        }
+        Some('[') => {
+            chars.next();
+            let mut values = Vec::new();
+            loop {
+                skip_whitespace(chars);
+                if chars.peek() == Some(&']') {
+                    chars.next();
+                    return Ok(Value::Array(values));
+                }
+                values.push(parse_value(chars)?);
+                skip_whitespace(chars);
+                match chars.next() {
+                    Some(',') => {}
+                    Some(']') => return Ok(Value::Array(values)),
+                    _ => return Err("expected `,` or `]` in array".to_string()),
+                }
+            }
+        }
+        Some(_) => {
+            let mut word = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_ascii_alphanumeric() || c == '_' || c == '+' || c == '-' {
+                    word.push(c);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            match word.as_str() {
+                "true" => Ok(Value::Boolean(true)),
+                "false" => Ok(Value::Boolean(false)),
+                _ => word
+                    .replace('_', "")
+                    .parse()
+                    .map(Value::Integer)
+                    .map_err(|_| format!("unsupported value `{}`", word)),
+            }
+        }
+        None => Err("missing value".to_string()),
+    }
+}
+
+/// Parses the rest of a basic string, after its opening quote.
+fn parse_basic_string(chars: &mut Peekable<Chars<'_>>) -> Result<String, String> {
+    let mut string = String::new();
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(string),
+            Some('\\') => match chars.next() {
+                Some('n') => string.push('\n'),
+                Some('t') => string.push('\t'),
+                Some('r') => string.push('\r'),
+                Some('"') => string.push('"'),
+                Some('\\') => string.push('\\'),
+                Some('u') => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    let c = u32::from_str_radix(&hex, 16)
+                        .ok()
+                        .and_then(std::char::from_u32)
+                        .ok_or_else(|| format!("invalid escape `\\u{}`", hex))?;
+                    string.push(c);
+                }
+                Some(c) => return Err(format!("invalid escape `\\{}`", c)),
+                None => return Err("unterminated string".to_string()),
+            },
+            Some('\n') | None => return Err("unterminated string".to_string()),
+            Some(c) => string.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries(input: &str) -> Vec<(usize, String, Value)> {
+        parse(input)
+            .unwrap()
+            .into_iter()
+            .map(|entry| (entry.line, entry.key, entry.value))
+            .collect()
+    }
+
+    fn error(input: &str) -> (usize, String) {
+        parse(input).unwrap_err()
+    }
+
+    fn string(s: &str) -> Value {
+        Value::String(s.to_string())
+    }
+
+    #[test]
+    fn keys_are_prefixed_with_their_table() {
+        let input = "# a comment\nstrict = true\n\n[rules]\n\"quoted-key\" = 'x'\n[ other ] # c\nlimit = -3";
+        assert_eq!(
+            entries(input),
+            [
+                (2, "strict".to_string(), Value::Boolean(true)),
+                (5, "rules.quoted-key".to_string(), string("x")),
+                (7, "other.limit".to_string(), Value::Integer(-3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn values() {
+        let input = "a = \"x # y\" # z\nb = 1_000\nc = +5\nd = \"\\u00e9\\t\\\"\"\ne = ''";
+        let values: Vec<_> = entries(input)
+            .into_iter()
+            .map(|(_, _, value)| value)
+            .collect();
+        assert_eq!(
+            values,
+            [
+                string("x # y"),
+                Value::Integer(1000),
+                Value::Integer(5),
+                string("é\t\""),
+                string(""),
+            ]
+        );
+    }
+
+    #[test]
+    fn arrays_may_span_lines() {
+        let input = "types = [\n  \"feat\", # new features\n  [\"fix\"],\n  'a]',\n]\nnext = []";
+        assert_eq!(
+            entries(input),
+            [
+                (
+                    1,
+                    "types".to_string(),
+                    Value::Array(vec![
+                        string("feat"),
+                        Value::Array(vec![string("fix")]),
+                        string("a]"),
+                    ])
+                ),
+                (6, "next".to_string(), Value::Array(Vec::new())),
+            ]
+        );
+    }
+
+    #[test]
+    fn the_same_key_may_be_used_in_different_tables() {
+        let keys: Vec<_> = entries("a = 1\n[t]\na = 2")
+            .into_iter()
+            .map(|(_, key, _)| key)
+            .collect();
+        assert_eq!(keys, ["a", "t.a"]);
+    }
+
+    #[test]
+    fn errors_are_located_at_their_line() {
+        assert_eq!(error("a = 1\na = 2"), (2, "duplicate key `a`".to_string()));
+        assert_eq!(error("[t]\n[t]"), (2, "duplicate table `t`".to_string()));
+        assert_eq!(error("\n[[t]]"), (2, "invalid table header".to_string()));
+        assert_eq!(error("[t"), (1, "invalid table header".to_string()));
+        assert_eq!(error("[a b]"), (1, "invalid table name".to_string()));
+        assert_eq!(error("a"), (1, "expected `key = value`".to_string()));
+        assert_eq!(error("a b = 1"), (1, "invalid key".to_string()));
+        assert_eq!(error("a ="), (1, "missing value".to_string()));
+        assert_eq!(error("a = [1,\n2"), (1, "unterminated array".to_string()));
+        assert_eq!(
+            error("a = [1 2]"),
+            (1, "expected `,` or `]` in array".to_string())
+        );
+        assert_eq!(error("a = 'x"), (1, "unterminated string".to_string()));
+        assert_eq!(error("a = \"x"), (1, "unterminated string".to_string()));
+        assert_eq!(
+            error("a = \"\\q\""),
+            (1, "invalid escape `\\q`".to_string())
+        );
+        assert_eq!(
+            error("a = \"\\uzzzz\""),
+            (1, "invalid escape `\\uzzzz`".to_string())
+        );
+        assert_eq!(
+            error("a = 1.5"),
+            (1, "unexpected text after the value".to_string())
+        );
+        assert_eq!(error("a = yes"), (1, "unsupported value `yes`".to_string()));
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{Commit, ParseError, ParseOptions};
+use std::{
+    io::{self, BufRead, BufReader, Read},
+    str,
+};
+
+/// The delimiter that `git log --format=%B%x00` puts after each message.
+pub const LOG_DELIMITER: &str = "\0";
+
+/// An iterator over the commits contained in the output of `git log`.
+///
+/// The output is split into messages at a delimiter, each of which is then
+/// parsed into a [`Commit`]. Messages that fail to parse are returned as
+/// errors, without stopping the iteration. The positions of those errors are
+/// relative to the start of the message, not the whole output.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::Log;
+///
+/// let output = "feat: add a log parser\n\0\nfix: parse empty logs\n\0\n";
+/// let commits: Vec<_> = Log::new(output).collect::<Result<_, _>>().unwrap();
+/// assert_eq!(commits.len(), 2);
+/// assert_eq!(commits[1].ty, "fix");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Log<'a> {
+    rest: &'a str,
+    delimiter: &'a str,
+    options: ParseOptions,
+}
+
+impl<'a> Log<'a> {
+    /// Creates an iterator over NUL-delimited messages, as produced by
+    /// `git log --format=%B%x00`.
+    pub fn new(output: &'a str) -> Self {
+        Self::with_delimiter(output, LOG_DELIMITER)
+    }
+
+    /// Creates an iterator over messages separated by `delimiter`.
+    pub fn with_delimiter(output: &'a str, delimiter: &'a str) -> Self {
+        Self {
+            rest: output,
+            delimiter,
+            options: ParseOptions::default(),
+        }
+    }
+
+    /// Sets the options used to parse each message.
+    pub fn options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Returns the next raw message, skipping the empty ones.
+    fn next_message(&mut self) -> Option<&'a str> {
+        while !self.rest.is_empty() {
+            let (message, rest) = match self.rest.find(self.delimiter) {
+                Some(idx) if !self.delimiter.is_empty() => {
+                    (&self.rest[..idx], &self.rest[idx + self.delimiter.len()..])
+                }
+                _ => (self.rest, ""),
+            };
+            self.rest = rest;
+
+            if let Some(message) = trim_record(message) {
+                return Some(message);
+            }
+        }
+        None
+    }
+}
+
+impl<'a> Iterator for Log<'a> {
+    type Item = Result<Commit<'a>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let message = self.next_message()?;
+        Some(Commit::parse_with(message, &self.options))
+    }
+}
+
+/// A reader of delimited commit messages, such as the output of
+/// `git log --format=%B%x00`.
+///
+/// In contrast to [`Log`], the messages are read incrementally, so the whole
+/// output never has to be kept in memory. Each message is only valid until
+/// the next one is read, which is why this type does not implement
+/// [`Iterator`].
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{Commit, CommitStream};
+///
+/// let output: &[u8] = b"feat: stream commits\n\0\nfix: handle partial reads\n\0\n";
+/// let mut stream = CommitStream::new(output);
+/// while let Some(message) = stream.next_message() {
+///     let commit = Commit::parse(message.unwrap()).unwrap();
+///     println!("{}", commit.ty);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct CommitStream<R> {
+    reader: BufReader<R>,
+    delimiter: u8,
+    buf: Vec<u8>,
+}
+
+impl<R: Read> CommitStream<R> {
+    /// Creates a stream of NUL-delimited messages.
+    pub fn new(reader: R) -> Self {
+        Self::with_delimiter(reader, 0)
+    }
+
+    /// Creates a stream of messages separated by the `delimiter` byte.
+    pub fn with_delimiter(reader: R, delimiter: u8) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            delimiter,
+            buf: Vec::new(),
+        }
+    }
+
+    /// Reads the next message, skipping the empty ones.
+    ///
+    /// Returns `None` once the reader is exhausted. Messages that are not
+    /// valid UTF-8 are reported as an [`io::ErrorKind::InvalidData`] error.
+    pub fn next_message(&mut self) -> Option<io::Result<&str>> {
+        loop {
+            self.buf.clear();
+            match self.reader.read_until(self.delimiter, &mut self.buf) {
+                Ok(0) => return None,
+                Ok(_) => {}
+                Err(e) => return Some(Err(e)),
+            }
+            if self.buf.last() == Some(&self.delimiter) {
+                self.buf.pop();
+            }
+
+            let message = match str::from_utf8(&self.buf) {
+                Ok(message) => message,
+                Err(e) => return Some(Err(io::Error::new(io::ErrorKind::InvalidData, e))),
+            };
+            if trim_record(message).is_some() {
+                break;
+            }
+        }
+
+        // Borrowing from the buffer inside of the loop is rejected by the
+        // borrow checker, so the already validated message is looked up again.
+        let message = str::from_utf8(&self.buf).expect("validated above");
+        trim_record(message).map(Ok)
+    }
+}
+
+/// Removes the line feeds that git puts between the entries of its output.
+///
+/// Returns `None` for records that do not contain a message.
+fn trim_record(record: &str) -> Option<&str> {
+    let message = record.trim_start_matches(|c| c == '\n' || c == '\r');
+    if message.trim().is_empty() {
+        None
+    } else {
+        Some(message)
+    }
+}
@@ -0,0 +1,311 @@
+//! Rendering of commits as Markdown, with references, commit hashes and
+//! mentions linked to the repository's forge.
+
+use crate::{link, mention, reference, Commit, FooterSeparator, Span, BREAKING_CHANGE_MARKER};
+use std::fmt::{self, Write};
+
+/// The characters that are escaped in plain text, so that they are not
+/// rendered as Markdown or HTML.
+const METACHARACTERS: &[char] = &['\\', '*', '_', '[', ']', '<', '>', '`'];
+
+/// The kind of forge that hosts a repository, which decides how its URLs
+/// are built.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Forge {
+    /// GitHub, with URLs like `https://github.com/owner/repo/issues/1`.
+    GitHub,
+    /// GitLab, with URLs like `https://gitlab.com/owner/repo/-/issues/1`.
+    GitLab,
+    /// Gitea and Forgejo, with URLs like `https://codeberg.org/owner/repo/issues/1`.
+    Gitea,
+}
+
+/// The repository that the references of a commit are linked to.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{Forge, LinkContext};
+///
+/// let context = LinkContext::new("https://gitlab.com/owner/repo", Forge::GitLab);
+/// assert_eq!(context.issue_url(None, None, 7), "https://gitlab.com/owner/repo/-/issues/7");
+/// assert_eq!(context.user_url("jane"), "https://gitlab.com/jane");
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LinkContext<'a> {
+    /// The URL of the repository, like `https://github.com/owner/repo`.
+    pub repository: &'a str,
+    /// The forge that hosts the repository.
+    pub forge: Forge,
+}
+
+impl<'a> LinkContext<'a> {
+    /// Creates a context for the repository at the given URL.
+    pub fn new(repository: &'a str, forge: Forge) -> Self {
+        Self { repository, forge }
+    }
+
+    /// Returns the URL of an issue.
+    ///
+    /// If `owner` and `repo` are given, the issue belongs to that repository
+    /// of the same forge instead of this one.
+    pub fn issue_url(&self, owner: Option<&str>, repo: Option<&str>, number: u64) -> String {
+        let repository = match (owner, repo) {
+            (Some(owner), Some(repo)) => format!("{}/{}/{}", self.host(), owner, repo),
+            _ => self.repository().to_string(),
+        };
+        match self.forge {
+            Forge::GitLab => format!("{}/-/issues/{}", repository, number),
+            Forge::GitHub | Forge::Gitea => format!("{}/issues/{}", repository, number),
+        }
+    }
+
+    /// Returns the URL of a commit.
+    pub fn commit_url(&self, hash: &str) -> String {
+        match self.forge {
+            Forge::GitLab => format!("{}/-/commit/{}", self.repository(), hash),
+            Forge::GitHub | Forge::Gitea => format!("{}/commit/{}", self.repository(), hash),
+        }
+    }
+
+    /// Returns the URL of the profile of a user, or of a team for mentions
+    /// like `org/team`.
+    pub fn user_url(&self, user: &str) -> String {
+        let mut parts = user.splitn(2, '/');
+        match (self.forge, parts.next(), parts.next()) {
+            (Forge::GitHub, Some(org), Some(team)) => {
+                format!("{}/orgs/{}/teams/{}", self.host(), org, team)
+            }
+            _ => format!("{}/{}", self.host(), user),
+        }
+    }
+
+    /// Returns the URL of the repository without a trailing slash.
+    fn repository(&self) -> &'a str {
+        self.repository.trim_end_matches('/')
+    }
+
+    /// Returns the scheme and host of the repository's URL.
+    fn host(&self) -> &'a str {
+        let repository = self.repository();
+        let authority = repository.find("://").map_or(0, |idx| idx + 3);
+        let end = repository[authority..]
+            .find('/')
+            .map_or(repository.len(), |idx| authority + idx);
+        &repository[..end]
+    }
+}
+
+/// Writes a commit as Markdown.
+///
+/// The header is written with its type and scope in bold, followed by the
+/// body and a list of the footers. All text is escaped, so that only the
+/// generated formatting and links are rendered.
+pub(crate) fn write_commit<W: Write>(
+    w: &mut W,
+    commit: &Commit<'_>,
+    context: &LinkContext<'_>,
+) -> fmt::Result {
+    if let Some(autosquash) = commit.autosquash {
+        w.write_str(autosquash.prefix())?;
+    }
+    if let Some(gitmoji) = commit.gitmoji {
+        write!(w, "{} ", gitmoji)?;
+    }
+    w.write_str("**")?;
+    write_escaped(w, commit.ty, false)?;
+    if let Some(scope) = commit.scope {
+        w.write_char('(')?;
+        write_escaped(w, scope, false)?;
+        w.write_char(')')?;
+    }
+    if commit.is_breaking_change {
+        w.write_char(BREAKING_CHANGE_MARKER)?;
+    }
+    w.write_str(":** ")?;
+    write_linked(w, commit.desc, context, false)?;
+
+    if let Some(body) = commit.body.filter(|body| !body.trim().is_empty()) {
+        w.write_str("\n\n")?;
+        write_linked(w, body.trim_end(), context, true)?;
+    }
+
+    if !commit.footer.is_empty() {
+        w.write_char('\n')?;
+    }
+    for footer in &commit.footer {
+        let text;
+        w.write_str("\n- **")?;
+        write_escaped(w, footer.token, false)?;
+        let value = match footer.separator {
+            FooterSeparator::SpaceHashTag => {
+                text = format!("#{}", footer.value);
+                &text
+            }
+            separator => {
+                write_escaped(w, separator.as_str().trim_end(), false)?;
+                footer.value
+            }
+        };
+        w.write_str("** ")?;
+        // Continuation lines are indented so that they stay in the list
+        // item.
+        for (idx, line) in value.lines().enumerate() {
+            if idx > 0 {
+                w.write_str("\n  ")?;
+            }
+            write_linked(w, line, context, idx > 0)?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes `text` with its references, commit hashes and mentions turned into
+/// Markdown links, escaping the rest of it. Existing links are written as
+/// they are, for Markdown to link them.
+///
+/// `line_start` tells whether `text` is written at the start of a line.
+fn write_linked<W: Write>(
+    w: &mut W,
+    text: &str,
+    context: &LinkContext<'_>,
+    line_start: bool,
+) -> fmt::Result {
+    let links: Vec<_> = link::links(text)
+        .into_iter()
+        .map(|link| Span::of(text, link))
+        .collect();
+
+    let mut targets: Vec<(Span, String)> = reference::locate(text)
+        .into_iter()
+        .map(|(span, reference)| {
+            let url = context.issue_url(reference.owner, reference.repo, reference.number);
+            (span, url)
+        })
+        .collect();
+    targets.extend(mention::scan(text).into_iter().map(|user| {
+        let span = Span::of(text, user);
+        (Span::new(span.start - 1, span.end), context.user_url(user))
+    }));
+    targets.extend(
+        hashes(text)
+            .into_iter()
+            .map(|hash| (Span::of(text, hash), context.commit_url(hash))),
+    );
+    targets.sort_by_key(|(span, _)| span.start);
+
+    let mut targets: Vec<(Span, Option<String>)> = targets
+        .into_iter()
+        .filter(|(span, _)| {
+            let overlaps = |other: &Span| span.start < other.end && other.start < span.end;
+            !links.iter().any(overlaps)
+        })
+        .map(|(span, url)| (span, Some(url)))
+        .collect();
+    targets.extend(links.into_iter().map(|span| (span, None)));
+    targets.sort_by_key(|(span, _)| span.start);
+
+    let mut written = 0;
+    for (span, url) in targets {
+        if span.start < written {
+            continue;
+        }
+        let before = &text[written..span.start];
+        write_escaped(w, before, starts_line(&text[..written], line_start))?;
+        let target = &text[span.start..span.end];
+        match url {
+            Some(url) => {
+                w.write_char('[')?;
+                write_escaped(w, target, false)?;
+                write!(w, "]({})", url)?;
+            }
+            // A `<` would end the link and start an HTML tag.
+            None => w.write_str(&target.replace('<', "\\<"))?,
+        }
+        written = span.end;
+    }
+    write_escaped(
+        w,
+        &text[written..],
+        starts_line(&text[..written], line_start),
+    )
+}
+
+/// Returns `true` if the text following `before` starts a line, ignoring
+/// indentation. `line_start` tells whether `before` starts one.
+fn starts_line(before: &str, line_start: bool) -> bool {
+    let (is_first_line, last_line) = match before.rfind('\n') {
+        Some(idx) => (false, &before[idx + 1..]),
+        None => (true, before),
+    };
+    (line_start || !is_first_line) && last_line.trim().is_empty()
+}
+
+/// Writes `text` with the [`METACHARACTERS`] escaped, as well as a `#` that
+/// starts a line, which would make it a heading.
+///
+/// `line_start` tells whether `text` is written at the start of a line.
+fn write_escaped<W: Write>(w: &mut W, text: &str, mut line_start: bool) -> fmt::Result {
+    for c in text.chars() {
+        if METACHARACTERS.contains(&c) || (line_start && c == '#') {
+            w.write_char('\\')?;
+        }
+        w.write_char(c)?;
+        if c == '\n' {
+            line_start = true;
+        } else if !c.is_whitespace() {
+            line_start = false;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the words of `text` that look like abbreviated or full commit
+/// hashes, which are 7 to 40 hexadecimal digits with at least one letter and
+/// one digit.
+fn hashes(text: &str) -> Vec<&str> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| {
+            (7..=40).contains(&word.len())
+                && word.chars().all(|c| c.is_ascii_hexdigit())
+                && word.chars().any(|c| c.is_ascii_digit())
+                && word.chars().any(|c| c.is_ascii_alphabetic())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn markdown(message: &str) -> String {
+        let context = LinkContext::new("https://github.com/owner/repo", Forge::GitHub);
+        Commit::parse(message).unwrap().to_markdown(&context)
+    }
+
+    #[test]
+    fn metacharacters_are_escaped() {
+        assert_eq!(
+            markdown("fix: handle *args and [x](y)\n\n<script>alert(1)</script>\n# not a heading"),
+            "**fix:** handle \\*args and \\[x\\](y)\n\n\
+             \\<script\\>alert(1)\\</script\\>\n\\# not a heading"
+        );
+        assert_eq!(
+            markdown("fix(a_b): `x`\n\nRefs: snake_case,\n  # and more"),
+            "**fix(a\\_b):** \\`x\\`\n\n- **Refs:** snake\\_case,\n    \\# and more"
+        );
+    }
+
+    #[test]
+    fn links_are_not_escaped() {
+        assert_eq!(
+            markdown("docs: link https://example.com/a_b and #12"),
+            "**docs:** link https://example.com/a_b and \
+             [#12](https://github.com/owner/repo/issues/12)"
+        );
+        assert_eq!(
+            markdown("docs: see https://example.com/<b>"),
+            "**docs:** see https://example.com/\\<b\\>"
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use crate::Commit;
+
+/// Returns the distinct users mentioned in the body and footer values of a
+/// commit, in the order in which they first appear.
+pub(crate) fn mentions<'a>(commit: &Commit<'a>) -> Vec<&'a str> {
+    let texts = commit
+        .body
+        .into_iter()
+        .chain(commit.footer.iter().map(|footer| footer.value));
+
+    let mut mentions = Vec::new();
+    for text in texts {
+        for mention in scan(text) {
+            if !mentions.contains(&mention) {
+                mentions.push(mention);
+            }
+        }
+    }
+    mentions
+}
+
+/// Returns the mentions in a text, without the `@`.
+///
+/// A mention is a user name of letters, digits and hyphens, optionally
+/// followed by the `/team` of an organization. Addresses like
+/// `jane@example.com` are not mentions.
+pub(crate) fn scan(text: &str) -> Vec<&str> {
+    let mut mentions = Vec::new();
+    for (idx, _) in text.match_indices('@') {
+        if text[..idx].ends_with(|c: char| c.is_alphanumeric() || c == '_' || c == '.' || c == '-')
+        {
+            continue;
+        }
+        let rest = &text[idx + 1..];
+        let mut len = name_len(rest);
+        if len == 0 {
+            continue;
+        }
+        if rest[len..].starts_with('/') {
+            let team = name_len(&rest[len + 1..]);
+            if team > 0 {
+                len += 1 + team;
+            }
+        }
+        // A trailing hyphen is not part of the name, as in `@jane-`.
+        mentions.push(rest[..len].trim_end_matches('-'));
+    }
+    mentions
+}
+
+/// Returns the length of the user or team name at the start of `s`.
+fn name_len(s: &str) -> usize {
+    if !s.starts_with(|c: char| c.is_ascii_alphanumeric()) {
+        return 0;
+    }
+    s.find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+        .unwrap_or(s.len())
+}
@@ -0,0 +1,125 @@
+use crate::parser;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// What kind of reference a merge commit merged.
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum MergeKind {
+    /// `Merge branch 'name'`, as written by `git merge`.
+    Branch,
+    /// `Merge remote-tracking branch 'origin/name'`, as written by `git pull`.
+    RemoteTrackingBranch,
+    /// `Merge tag 'name'`.
+    Tag,
+    /// `Merge pull request #123 from owner/name`, as written by GitHub.
+    PullRequest,
+}
+
+/// A merge commit message as generated by git or GitHub.
+///
+/// Such messages do not follow the specification, but they are common enough
+/// in most histories that they should be recognized instead of being treated
+/// as errors.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{Merge, MergeKind};
+///
+/// let merge = Merge::parse("Merge pull request #42 from octocat/parser\n\nfeat: add a parser").unwrap();
+/// assert_eq!(merge.kind, MergeKind::PullRequest);
+/// assert_eq!(merge.pull_request, Some(42));
+/// assert_eq!(merge.source, "octocat/parser");
+/// assert_eq!(merge.body, Some("feat: add a parser"));
+/// ```
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Merge<'a> {
+    /// The optional body. For pull requests, GitHub puts the title there.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub body: Option<&'a str>,
+    /// The kind of the merged reference.
+    pub kind: MergeKind,
+    /// The number of the merged pull request.
+    pub pull_request: Option<u64>,
+    /// The merged branch or tag. For pull requests, this is the
+    /// `owner/branch` the changes came from.
+    pub source: &'a str,
+    /// The branch that was merged into, if it is part of the message.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub target: Option<&'a str>,
+}
+
+impl<'a> Merge<'a> {
+    /// Parses a merge commit message.
+    ///
+    /// Returns `None` if the header is not one of the known merge formats.
+    pub fn parse(input: &'a str) -> Option<Self> {
+        let (header, rest) = parser::split_first_line(input);
+        let header = header.trim_end();
+        let body = rest.map(str::trim).filter(|body| !body.is_empty());
+
+        if header.starts_with("Merge pull request #") {
+            let rest = &header["Merge pull request #".len()..];
+            let number_end = rest.find(' ').unwrap_or(rest.len());
+            let number = rest[..number_end].parse().ok()?;
+            let rest = &rest[number_end..];
+            let source = if rest.starts_with(" from ") {
+                &rest[" from ".len()..]
+            } else {
+                return None;
+            };
+            return Some(Self {
+                body,
+                kind: MergeKind::PullRequest,
+                pull_request: Some(number),
+                source,
+                target: None,
+            });
+        }
+
+        let (kind, rest) = [
+            ("Merge branch ", MergeKind::Branch),
+            (
+                "Merge remote-tracking branch ",
+                MergeKind::RemoteTrackingBranch,
+            ),
+            ("Merge tag ", MergeKind::Tag),
+        ]
+        .iter()
+        .find(|(prefix, _)| header.starts_with(prefix))
+        .map(|&(prefix, kind)| (kind, &header[prefix.len()..]))?;
+        let (source, rest) = split_quoted(rest)?;
+
+        // git mentions the repository of a pulled branch, `of <url>`, before
+        // the branch that was merged into.
+        let target = rest
+            .find(" into ")
+            .map(|idx| unquote(&rest[idx + " into ".len()..]));
+        Some(Self {
+            body,
+            kind,
+            pull_request: None,
+            source,
+            target,
+        })
+    }
+}
+
+/// Splits off a name in single quotes from the start of `s`.
+fn split_quoted(s: &str) -> Option<(&str, &str)> {
+    if !s.starts_with('\'') {
+        return None;
+    }
+    let end = s[1..].find('\'')? + 1;
+    Some((&s[1..end], &s[end + 1..]))
+}
+
+/// Removes the single quotes around a name, as written by GitLab.
+fn unquote(s: &str) -> &str {
+    match split_quoted(s) {
+        Some((name, "")) => name,
+        _ => s,
+    }
+}
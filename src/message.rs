@@ -0,0 +1,167 @@
+use crate::{parser, Autosquash, Commit, Merge, ParseOptions};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A commit message that may or may not follow the specification.
+///
+/// Changelog generators usually have to deal with histories in which only
+/// some of the commits are conventional. Parsing into this type never fails,
+/// so plain messages can still be listed or counted.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::ParsedMessage;
+///
+/// let messages = ["feat: add a parser", "Initial commit"];
+/// let conventional = messages
+///     .iter()
+///     .map(|message| ParsedMessage::parse(message))
+///     .filter(ParsedMessage::is_conventional)
+///     .count();
+/// assert_eq!(conventional, 1);
+/// ```
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ParsedMessage<'a> {
+    /// A message that follows the specification.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    Conventional(Commit<'a>),
+    /// A merge commit generated by git or GitHub.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    Merge(Merge<'a>),
+    /// A message that could not be parsed, as written.
+    Unconventional(&'a str),
+}
+
+impl<'a> ParsedMessage<'a> {
+    /// Parses a commit message, falling back to a merge or an unconventional
+    /// message if it does not follow the specification.
+    pub fn parse(input: &'a str) -> Self {
+        Self::parse_with(input, &ParseOptions::default())
+    }
+
+    /// Parses a commit message using the given options, falling back to a
+    /// merge or an unconventional message if it cannot be parsed.
+    pub fn parse_with(input: &'a str, options: &ParseOptions) -> Self {
+        match Commit::parse_with(input, options) {
+            Ok(commit) => ParsedMessage::Conventional(commit),
+            Err(_) => match Merge::parse(input) {
+                Some(merge) => ParsedMessage::Merge(merge),
+                None => ParsedMessage::Unconventional(input),
+            },
+        }
+    }
+
+    /// Returns `true` if the message follows the specification.
+    pub fn is_conventional(&self) -> bool {
+        match self {
+            ParsedMessage::Conventional(_) => true,
+            ParsedMessage::Merge(_) | ParsedMessage::Unconventional(_) => false,
+        }
+    }
+
+    /// Returns the commit if the message follows the specification.
+    pub fn as_commit(&self) -> Option<&Commit<'a>> {
+        match self {
+            ParsedMessage::Conventional(commit) => Some(commit),
+            ParsedMessage::Merge(_) | ParsedMessage::Unconventional(_) => None,
+        }
+    }
+
+    /// Returns the merge if the message is a merge commit.
+    pub fn as_merge(&self) -> Option<&Merge<'a>> {
+        match self {
+            ParsedMessage::Merge(merge) => Some(merge),
+            ParsedMessage::Conventional(_) | ParsedMessage::Unconventional(_) => None,
+        }
+    }
+
+    /// Returns `true` if the message is a merge commit.
+    pub fn is_merge(&self) -> bool {
+        self.as_merge().is_some()
+    }
+
+    /// Returns `true` if the message reverts an earlier commit, either as a
+    /// commit of the `revert` type or as generated by `git revert`.
+    ///
+    /// See [`Revert`](crate::Revert) for the reverted commits.
+    pub fn is_revert(&self) -> bool {
+        match self {
+            ParsedMessage::Conventional(commit) => commit.is_revert(),
+            ParsedMessage::Merge(_) => false,
+            ParsedMessage::Unconventional(message) => message.starts_with("Revert \""),
+        }
+    }
+
+    /// Returns `true` if the message is meant to be squashed into an earlier
+    /// commit, as its header has a `fixup! `, `squash! ` or `amend! ` prefix.
+    ///
+    /// Unlike [`Commit::is_fixup`], this also works for messages parsed
+    /// without stripping the prefixes.
+    pub fn is_fixup(&self) -> bool {
+        match self {
+            ParsedMessage::Conventional(commit) => commit.is_fixup(),
+            ParsedMessage::Merge(_) => false,
+            ParsedMessage::Unconventional(message) => Autosquash::detect(message).is_some(),
+        }
+    }
+
+    /// Returns `true` if the message is marked as work in progress.
+    ///
+    /// For conventional messages, see [`Commit::is_wip`]. Other messages are
+    /// if their subject starts with `WIP` or `[WIP]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::ParsedMessage;
+    ///
+    /// let messages = ["feat: add a parser", "fixup! feat: add a parser", "WIP", "Merge branch 'dev'"];
+    /// let noise = messages
+    ///     .iter()
+    ///     .map(|message| ParsedMessage::parse(message))
+    ///     .filter(|message| message.is_merge() || message.is_fixup() || message.is_wip())
+    ///     .count();
+    /// assert_eq!(noise, 3);
+    /// ```
+    pub fn is_wip(&self) -> bool {
+        match self {
+            ParsedMessage::Conventional(commit) => commit.is_wip(),
+            ParsedMessage::Merge(_) => false,
+            ParsedMessage::Unconventional(message) => {
+                is_wip_subject(parser::split_first_line(message).0)
+            }
+        }
+    }
+
+    /// Converts the message into its commit, if it follows the
+    /// specification.
+    pub fn into_commit(self) -> Option<Commit<'a>> {
+        match self {
+            ParsedMessage::Conventional(commit) => Some(commit),
+            ParsedMessage::Merge(_) | ParsedMessage::Unconventional(_) => None,
+        }
+    }
+}
+
+/// Returns `true` if `subject` starts with the word `WIP`, in any case, or
+/// with `[WIP]`.
+pub(crate) fn is_wip_subject(subject: &str) -> bool {
+    let subject = subject.trim_start();
+    let subject = if subject.starts_with('[') {
+        &subject[1..]
+    } else {
+        subject
+    };
+    subject
+        .get(..3)
+        .map_or(false, |word| word.eq_ignore_ascii_case("wip"))
+        && !subject[3..].starts_with(char::is_alphanumeric)
+}
+
+impl<'a> From<Commit<'a>> for ParsedMessage<'a> {
+    fn from(commit: Commit<'a>) -> Self {
+        ParsedMessage::Conventional(commit)
+    }
+}
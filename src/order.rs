@@ -0,0 +1,58 @@
+use crate::{Commit, DEFAULT_TYPES};
+use std::cmp::Ordering;
+
+/// The order of the well-known types when sorting commits, which follows the
+/// sections of a typical changelog.
+///
+/// This is the order of the [`DEFAULT_TYPES`].
+pub const TYPE_ORDER: &[&str] = DEFAULT_TYPES;
+
+/// Returns the position of a type in the [`TYPE_ORDER`], with all other types
+/// after the well-known ones.
+fn type_rank(ty: &str) -> usize {
+    TYPE_ORDER
+        .iter()
+        .position(|known| known.eq_ignore_ascii_case(ty))
+        .unwrap_or_else(|| TYPE_ORDER.len())
+}
+
+impl Ord for Commit<'_> {
+    /// Orders commits by their type, then their scope and then their
+    /// description.
+    ///
+    /// Types are ordered as in the [`TYPE_ORDER`], and other types after
+    /// those alphabetically. Commits without a scope come before those with
+    /// one. Commits that agree on all of these are ordered by the remaining
+    /// components, breaking changes first, so that the order is consistent
+    /// with equality.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::Commit;
+    ///
+    /// let messages = ["docs: add a guide", "fix(cli): exit early", "feat: add a flag", "fix: trim"];
+    /// let mut commits: Vec<_> = messages.iter().map(|m| Commit::parse(m).unwrap()).collect();
+    /// commits.sort();
+    /// let sorted: Vec<_> = commits.iter().map(|commit| commit.summary()).collect();
+    /// assert_eq!(sorted, ["feat: add a flag", "fix: trim", "fix(cli): exit early", "docs: add a guide"]);
+    /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        type_rank(self.ty)
+            .cmp(&type_rank(other.ty))
+            .then_with(|| self.ty.cmp(other.ty))
+            .then_with(|| self.scope.cmp(&other.scope))
+            .then_with(|| self.desc.cmp(other.desc))
+            .then_with(|| other.is_breaking_change.cmp(&self.is_breaking_change))
+            .then_with(|| self.body.cmp(&other.body))
+            .then_with(|| self.footer.cmp(&other.footer))
+            .then_with(|| self.autosquash.cmp(&other.autosquash))
+            .then_with(|| self.gitmoji.cmp(&other.gitmoji))
+    }
+}
+
+impl PartialOrd for Commit<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
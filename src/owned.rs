@@ -0,0 +1,214 @@
+use crate::{Autosquash, Commit, Extensions, Footer, FooterSeparator, ParseError};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+/// A commit that owns its components instead of borrowing them from the
+/// message.
+///
+/// It can be stored independently of the message, for example in a
+/// long-lived index or a cache of a parsed history. Use
+/// [`CommitOwned::as_commit`] to get a [`Commit`] back, which borrows from
+/// the owned commit. It is also deserialized without borrowing from the input.
+/// That makes it work with any serde format, including binary ones like
+/// `bincode` and `postcard` and readers that cannot lend their data.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use conventional_commits_types::{Commit, CommitOwned};
+///
+/// let commit = Commit::parse("feat(cache)!: store parsed commits\n\nRefs: #3").unwrap();
+/// let owned = CommitOwned::from(&commit);
+/// let bytes = serde_json::to_vec(&owned).unwrap();
+///
+/// let cached: CommitOwned = serde_json::from_reader(&bytes[..]).unwrap();
+/// assert_eq!(cached.as_commit(), commit);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CommitOwned {
+    /// The kind of the autosquash prefix of the header, like `fixup! `.
+    pub autosquash: Option<Autosquash>,
+    /// The optional body.
+    pub body: Option<String>,
+    /// The mandatory description.
+    pub desc: String,
+    /// Extra data attached by tools, which is not part of the message.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Extensions::is_empty")
+    )]
+    pub extensions: Extensions,
+    /// A list of footers. Empty when none are part of the commit message.
+    pub footer: Vec<FooterOwned>,
+    /// The gitmoji in front of the type, like `✨` or `:sparkles:`.
+    pub gitmoji: Option<String>,
+    /// Whether the header has the `!` breaking change marker.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "crate::is_false")
+    )]
+    pub has_breaking_marker: bool,
+    /// Whether the commit introduces a breaking change.
+    pub is_breaking_change: bool,
+    /// The optional scope.
+    pub scope: Option<String>,
+    /// The mandatory type.
+    pub ty: String,
+}
+
+impl CommitOwned {
+    /// Parses a commit message into an owned commit.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Commit::parse(input).map(Self::from)
+    }
+
+    /// Returns a commit that borrows the components of this one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::CommitOwned;
+    ///
+    /// let owned = CommitOwned::parse("feat(index): keep commits around").unwrap();
+    /// assert_eq!(owned.as_commit().scopes().collect::<Vec<_>>(), ["index"]);
+    /// ```
+    pub fn as_commit(&self) -> Commit<'_> {
+        Commit {
+            autosquash: self.autosquash,
+            body: self.body.as_deref(),
+            desc: &self.desc,
+            extensions: self.extensions.clone(),
+            footer: self.footer.iter().map(FooterOwned::as_footer).collect(),
+            gitmoji: self.gitmoji.as_deref(),
+            has_breaking_marker: self.has_breaking_marker,
+            is_breaking_change: self.is_breaking_change,
+            scope: self.scope.as_deref(),
+            ty: &self.ty,
+        }
+    }
+}
+
+impl From<&Commit<'_>> for CommitOwned {
+    fn from(commit: &Commit<'_>) -> Self {
+        Self {
+            autosquash: commit.autosquash,
+            body: commit.body.map(str::to_string),
+            desc: commit.desc.to_string(),
+            extensions: commit.extensions.clone(),
+            footer: commit.footer.iter().map(FooterOwned::from).collect(),
+            gitmoji: commit.gitmoji.map(str::to_string),
+            has_breaking_marker: commit.has_breaking_marker,
+            is_breaking_change: commit.is_breaking_change,
+            scope: commit.scope.map(str::to_string),
+            ty: commit.ty.to_string(),
+        }
+    }
+}
+
+impl From<Commit<'_>> for CommitOwned {
+    fn from(commit: Commit<'_>) -> Self {
+        Self::from(&commit)
+    }
+}
+
+impl FromStr for CommitOwned {
+    type Err = ParseError;
+
+    /// Parses a commit message into an owned commit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::CommitOwned;
+    ///
+    /// let commit: CommitOwned = "fix(cli): accept --all".parse().unwrap();
+    /// assert_eq!(commit.scope.as_deref(), Some("cli"));
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse(input)
+    }
+}
+
+impl PartialEq<Commit<'_>> for CommitOwned {
+    fn eq(&self, other: &Commit<'_>) -> bool {
+        self.as_commit() == *other
+    }
+}
+
+impl PartialEq<CommitOwned> for Commit<'_> {
+    fn eq(&self, other: &CommitOwned) -> bool {
+        *self == other.as_commit()
+    }
+}
+
+impl fmt::Display for CommitOwned {
+    /// Formats the commit as its borrowed counterpart does.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_commit().fmt(f)
+    }
+}
+
+/// A footer that owns its components.
+///
+/// The separator is stored as the string it stands for, like `": "`.
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FooterOwned {
+    /// The token of the footer.
+    pub token: String,
+    /// The separator between the token and the value.
+    pub separator: String,
+    /// The value of the footer.
+    pub value: String,
+}
+
+impl FooterOwned {
+    /// Returns a footer that borrows the components of this one.
+    pub fn as_footer(&self) -> Footer<'_> {
+        Footer::from(
+            &self.token,
+            FooterSeparator::from(self.separator.as_str()),
+            &self.value,
+        )
+    }
+}
+
+impl From<&Footer<'_>> for FooterOwned {
+    fn from(footer: &Footer<'_>) -> Self {
+        Self {
+            token: footer.token.to_string(),
+            separator: footer.separator.as_str().to_string(),
+            value: footer.value.to_string(),
+        }
+    }
+}
+
+impl From<Footer<'_>> for FooterOwned {
+    fn from(footer: Footer<'_>) -> Self {
+        Self::from(&footer)
+    }
+}
+
+impl fmt::Display for FooterOwned {
+    /// Formats the footer as its borrowed counterpart does.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_footer().fmt(f)
+    }
+}
+
+impl Hash for CommitOwned {
+    /// Hashes the commit as its borrowed counterpart does, so that the
+    /// representations can be used interchangeably as keys.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_commit().hash(state);
+    }
+}
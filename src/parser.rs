@@ -0,0 +1,787 @@
+//! A zero-copy parser for commit messages.
+//!
+//! Every component of the returned [`Commit`](crate::Commit) borrows from the
+//! parsed input. Lines may end in `\n` or `\r\n`, the carriage returns are
+//! not part of the header or footers, but they are kept inside of the body.
+
+use crate::{
+    link, strip_autosquash, trailer, Commit, CommitSpans, Convention, Encoding, Footer, FooterMode,
+    FooterSeparator, FooterSpans, Header, ParseError, ParseErrorKind, Span, SpecVersion,
+    ACKED_BY_TOKEN, BREAKING_CHANGE_MARKER, BREAKING_CHANGE_TOKEN, BREAKING_CHANGE_TOKEN_ALIAS,
+    CO_AUTHORED_BY_TOKEN, DEFAULT_TYPES, REVIEWED_BY_TOKEN, SCOPE_SEPARATORS, SEPARATOR_COLON,
+    SEPARATOR_HASHTAG, SIGNED_OFF_BY_TOKEN,
+};
+
+/// The maximum number of words that a footer token separated by spaces is
+/// recognized with.
+const MAX_SPACED_TOKEN_WORDS: usize = 3;
+
+/// The hyphenated tokens that are recognized when spelled with spaces, like
+/// `Signed off by`. Other paragraphs starting with a few words and a colon,
+/// like `In short: it works`, remain part of the body.
+const SPACED_TOKENS: &[&str] = &[
+    SIGNED_OFF_BY_TOKEN,
+    CO_AUTHORED_BY_TOKEN,
+    REVIEWED_BY_TOKEN,
+    ACKED_BY_TOKEN,
+];
+
+/// Options that control how commit messages are parsed.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ParseOptions {
+    /// Whether near-conventional messages are rejected.
+    ///
+    /// When disabled, the parser accepts messages with a missing space after
+    /// the colon, uppercase types, a missing empty line after the header,
+    /// well-known footer tokens spelled with spaces like `Signed off by`, a
+    /// `!` in front of the scope and surrounding whitespace. Such messages
+    /// are normalized where possible: whitespace is trimmed and
+    /// well-known types like `FEAT` are returned in their lowercase form.
+    /// Other types are kept as written, as the components of a commit
+    /// borrow from the input.
+    pub strict: bool,
+    /// Whether the `fixup! `, `squash! ` and `amend! ` prefixes of commits
+    /// created for an interactive rebase are removed from the header.
+    ///
+    /// The kind of the removed prefix is recorded on the parsed commit. When
+    /// disabled, such headers are not valid.
+    pub autosquash: bool,
+    /// Whether a leading gitmoji, like `✨ feat: ...` or
+    /// `:sparkles: feat: ...`, is recognized in front of the type.
+    ///
+    /// The gitmoji is recorded on the parsed commit. When disabled, such
+    /// headers are not valid.
+    pub gitmoji: bool,
+    /// The convention that messages have to follow.
+    pub convention: Convention,
+    /// The revision of the specification that messages have to follow.
+    pub spec_version: SpecVersion,
+    /// The encoding that [`Commit::parse_bytes`] assumes for messages that
+    /// are not valid UTF-8.
+    pub encoding: Encoding,
+    /// How the footers are recognized.
+    pub footer_mode: FooterMode,
+    /// The separators that are accepted between the token and the value of a
+    /// footer, tried in order.
+    ///
+    /// By default, these are `:<space>` and `<space>#`. Any other separator
+    /// is returned as [`FooterSeparator::Custom`].
+    pub footer_separators: Vec<String>,
+    /// The characters that separate the token and the value of trailers in
+    /// the [`FooterMode::GitTrailers`] mode, like git's `trailer.separators`
+    /// setting. By default, this is `:`.
+    pub trailer_separators: String,
+}
+
+impl ParseOptions {
+    /// Creates the default, strict options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates options for lenient parsing.
+    pub fn lenient() -> Self {
+        Self {
+            strict: false,
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for ParseOptions {
+    /// Returns the default options, which parse strictly.
+    fn default() -> Self {
+        Self {
+            strict: true,
+            autosquash: false,
+            gitmoji: false,
+            convention: Convention::default(),
+            spec_version: SpecVersion::default(),
+            encoding: Encoding::default(),
+            footer_mode: FooterMode::default(),
+            footer_separators: vec![SEPARATOR_COLON.to_string(), SEPARATOR_HASHTAG.to_string()],
+            trailer_separators: ":".to_string(),
+        }
+    }
+}
+
+/// Parses a whole commit message, keeping track of where each component is
+/// located.
+///
+/// Problems are pushed onto `errors` and parsing continues with a best-effort
+/// interpretation of the offending component.
+pub(crate) fn parse<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+    errors: &mut Vec<ParseError>,
+) -> (Commit<'a>, CommitSpans) {
+    let (header, rest) = split_first_line(input);
+    let (header, mut spans) = parse_header(header, options, errors);
+    let mut commit = Commit::from_header(header);
+
+    let rest = match rest {
+        Some(rest) if !is_blank(rest) => rest,
+        _ => return (commit, spans),
+    };
+    let content = match split_first_line(rest) {
+        (blank, content) if is_blank(blank) => content.unwrap_or(""),
+        (line, _) => {
+            if options.strict {
+                errors.push(error(input, ParseErrorKind::MissingBlankLine, line));
+            }
+            rest
+        }
+    };
+    let content = trim_blank_lines(content);
+
+    let footer_start = match options.footer_mode {
+        FooterMode::Conventional => find_footer_section(content, options),
+        FooterMode::GitTrailers => trailer::find_trailer_block(content, options),
+    };
+    let body = content[..footer_start].trim_end();
+    if !body.is_empty() {
+        commit.body = Some(body);
+        spans.body = Some(Span::of(input, body));
+        if options.spec_version.allows_breaking_change_in_body() {
+            commit.is_breaking_change |= has_breaking_paragraph(body);
+        }
+    }
+
+    // The footers are pushed as they are found, so that a single footer does
+    // not allocate at all.
+    let section = &content[footer_start..];
+    let mut push = |footer: Footer<'a>, footer_spans| {
+        if is_breaking_change_token(footer.token, options) {
+            commit.is_breaking_change = true;
+        }
+        commit.footer.push(footer);
+        spans.footer.push(footer_spans);
+    };
+    match options.footer_mode {
+        FooterMode::Conventional => split_footers(section, options, |text| {
+            if let Some((footer, footer_spans)) = parse_footer(input, text, options, errors) {
+                push(footer, footer_spans);
+            }
+        }),
+        FooterMode::GitTrailers => trailer::parse_trailers(input, section, options, push),
+    }
+
+    (commit, spans)
+}
+
+/// Parses the header line of a commit message, `type(scope)!: description`.
+///
+/// The returned spans are relative to the start of `line`.
+pub(crate) fn parse_header<'a>(
+    line: &'a str,
+    options: &ParseOptions,
+    errors: &mut Vec<ParseError>,
+) -> (Header<'a>, CommitSpans) {
+    let header = if options.strict { line } else { line.trim() };
+    let mut spans = CommitSpans {
+        header: Span::of(line, header),
+        ..CommitSpans::default()
+    };
+    let (autosquash, header) = if options.autosquash {
+        strip_autosquash(header)
+    } else {
+        (None, header)
+    };
+    let (gitmoji, header) = if options.gitmoji {
+        split_gitmoji(header)
+    } else {
+        (None, header)
+    };
+    spans.gitmoji = gitmoji.map(|gitmoji| Span::of(line, gitmoji));
+
+    let ty_end = match header.find(|c| c == '(' || c == BREAKING_CHANGE_MARKER || c == ':') {
+        Some(ty_end) => ty_end,
+        None => {
+            // Without any structure, the whole header is the best guess for a
+            // description.
+            let end = &header[header.len()..];
+            errors.push(error(line, ParseErrorKind::MissingSeparator, end));
+            spans.ty = Span::of(line, &header[..0]);
+            spans.desc = Span::of(line, header);
+            let header = Header {
+                autosquash,
+                desc: header,
+                gitmoji,
+                is_breaking_change: false,
+                scope: None,
+                ty: "",
+            };
+            return (header, spans);
+        }
+    };
+    let ty = &header[..ty_end];
+    spans.ty = Span::of(line, ty);
+    if ty.is_empty() {
+        errors.push(error(line, ParseErrorKind::MissingType, ty));
+    } else if ty.contains(char::is_whitespace) {
+        errors.push(error(line, ParseErrorKind::InvalidType, ty));
+    }
+    let ty = if !ty.chars().any(char::is_uppercase) {
+        ty
+    } else if options.strict {
+        errors.push(error(line, ParseErrorKind::InvalidType, ty));
+        ty
+    } else {
+        normalize_type(ty)
+    };
+    if options.strict && !ty.is_empty() && !options.convention.allows_type(ty) {
+        errors.push(error(line, ParseErrorKind::InvalidType, ty));
+    }
+
+    let mut rest = &header[ty_end..];
+    // `type!(scope):` is a common misspelling of `type(scope)!:`.
+    let mut misplaced_marker = None;
+    if rest.starts_with("!(") {
+        if options.strict {
+            errors.push(error(
+                line,
+                ParseErrorKind::MisplacedBreakingMarker,
+                &rest[..1],
+            ));
+        }
+        misplaced_marker = Some(&rest[..1]);
+        rest = &rest[1..];
+    }
+    let mut scope = None;
+    if rest.starts_with('(') {
+        match rest.find(')') {
+            Some(scope_end) => {
+                let mut inner = &rest[1..scope_end];
+                if !options.strict {
+                    inner = inner.trim();
+                }
+                if inner.is_empty() || inner.contains('(') {
+                    let scope = &rest[..=scope_end];
+                    errors.push(error(line, ParseErrorKind::InvalidScope, scope));
+                } else if options.strict
+                    && inner
+                        .split(SCOPE_SEPARATORS)
+                        .any(|part| part.trim().is_empty())
+                {
+                    errors.push(error(line, ParseErrorKind::InvalidScope, inner));
+                }
+                if !inner.is_empty() {
+                    scope = Some(inner);
+                    spans.scope = Some(Span::of(line, inner));
+                }
+                rest = &rest[scope_end + 1..];
+            }
+            None => {
+                let scope_end = rest.find(':').unwrap_or(rest.len());
+                errors.push(error(
+                    line,
+                    ParseErrorKind::InvalidScope,
+                    &rest[..scope_end],
+                ));
+                rest = &rest[scope_end..];
+            }
+        }
+    }
+
+    if rest.starts_with(BREAKING_CHANGE_MARKER) {
+        spans.breaking_marker = Some(Span::of(line, &rest[..1]));
+        rest = &rest[1..];
+    } else if let Some(marker) = misplaced_marker {
+        spans.breaking_marker = Some(Span::of(line, marker));
+    }
+    if let Some(marker) = spans.breaking_marker {
+        let allowed = options.convention != Convention::Angular
+            && options.spec_version.allows_breaking_marker();
+        if options.strict && !allowed {
+            errors.push(ParseError::at(
+                ParseErrorKind::UnsupportedBreakingMarker,
+                line,
+                marker,
+            ));
+        }
+    }
+    let is_breaking_change = spans.breaking_marker.is_some();
+
+    let desc = if rest.starts_with(SEPARATOR_COLON) {
+        &rest[SEPARATOR_COLON.len()..]
+    } else {
+        if options.strict || !rest.starts_with(':') {
+            errors.push(error(line, ParseErrorKind::MissingSeparator, &rest[..0]));
+        }
+        rest.trim_start_matches(':')
+    };
+    let trimmed = desc.trim();
+    let desc = if trimmed.is_empty() {
+        errors.push(error(line, ParseErrorKind::MissingDescription, desc));
+        trimmed
+    } else if desc.len() == trimmed.len() {
+        desc
+    } else {
+        if options.strict {
+            let whitespace = if desc.starts_with(char::is_whitespace) {
+                &desc[..desc.len() - desc.trim_start().len()]
+            } else {
+                &desc[trimmed.len()..]
+            };
+            errors.push(error(
+                line,
+                ParseErrorKind::UnexpectedWhitespace,
+                whitespace,
+            ));
+        }
+        trimmed
+    };
+
+    spans.desc = Span::of(line, desc);
+
+    let header = Header {
+        autosquash,
+        desc,
+        gitmoji,
+        is_breaking_change,
+        scope,
+        ty,
+    };
+    (header, spans)
+}
+
+/// Splits off a leading gitmoji, either an emoji like `✨` or a shortcode
+/// like `:sparkles:`, that is followed by a space.
+fn split_gitmoji(header: &str) -> (Option<&str>, &str) {
+    let len = if header.starts_with(':') {
+        header[1..]
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '+'))
+            .filter(|&end| end > 0 && header[end + 1..].starts_with(':'))
+            .map_or(0, |end| end + 2)
+    } else {
+        // Emoji may be made out of several code points, like a base
+        // character followed by a variation selector.
+        header
+            .find(|c: char| c.is_ascii() || c.is_alphanumeric() || c.is_whitespace())
+            .unwrap_or(header.len())
+    };
+    if len > 0 && header[len..].starts_with(' ') {
+        (Some(&header[..len]), header[len..].trim_start())
+    } else {
+        (None, header)
+    }
+}
+
+/// Returns the lowercase spelling of a well-known type, or the type itself.
+fn normalize_type(ty: &str) -> &str {
+    DEFAULT_TYPES
+        .iter()
+        .find(|known| known.eq_ignore_ascii_case(ty))
+        .map_or(ty, |known| known)
+}
+
+/// Returns the byte offset at which the footer section of `content` starts.
+///
+/// The footer section is the trailing run of paragraphs that each start with
+/// a footer, where paragraphs following a breaking change footer continue
+/// its value. If there is none, the length of `content` is returned.
+fn find_footer_section(content: &str, options: &ParseOptions) -> usize {
+    let mut start = None;
+    let mut at_paragraph_start = true;
+    // A breaking change note may contain any number of paragraphs, so the
+    // footer section cannot end before it.
+    let mut in_note = false;
+    let mut offset = 0;
+
+    for line in content.split('\n') {
+        if is_blank(line) {
+            at_paragraph_start = true;
+        } else {
+            if at_paragraph_start {
+                match split_footer(strip_cr(line), options) {
+                    None if in_note => {}
+                    None => start = None,
+                    Some((token, _, _)) => {
+                        if start.is_none() {
+                            start = Some(offset);
+                        }
+                        if is_breaking_change_token(token, options) {
+                            in_note = true;
+                        }
+                    }
+                }
+            }
+            at_paragraph_start = false;
+        }
+        offset += line.len() + 1;
+    }
+
+    start.unwrap_or(content.len())
+}
+
+/// Returns `true` if footers with the token `token` mark a breaking change.
+pub(crate) fn is_breaking_change_token(token: &str, options: &ParseOptions) -> bool {
+    match options.convention {
+        Convention::Conventional if options.spec_version.allows_breaking_change_alias() => {
+            token == BREAKING_CHANGE_TOKEN || token == BREAKING_CHANGE_TOKEN_ALIAS
+        }
+        _ => token == BREAKING_CHANGE_TOKEN,
+    }
+}
+
+/// Returns `true` if a paragraph of `body` starts with `BREAKING CHANGE: `.
+fn has_breaking_paragraph(body: &str) -> bool {
+    let mut at_paragraph_start = true;
+    for line in body.split('\n') {
+        if at_paragraph_start
+            && line.starts_with(BREAKING_CHANGE_TOKEN)
+            && line[BREAKING_CHANGE_TOKEN.len()..].starts_with(SEPARATOR_COLON)
+        {
+            return true;
+        }
+        at_paragraph_start = is_blank(line);
+    }
+    false
+}
+
+/// Splits the footer section into the texts of the individual footers and
+/// passes them to `f`, in order.
+///
+/// A footer continues on the following lines until the next footer starts.
+/// Blank lines between footers are not part of the texts.
+fn split_footers<'a>(section: &'a str, options: &ParseOptions, mut f: impl FnMut(&'a str)) {
+    let mut current: Option<(usize, usize)> = None;
+    let mut offset = 0;
+
+    for raw in section.split('\n') {
+        let line = strip_cr(raw);
+        let end = offset + line.len();
+        if split_footer(line, options).is_some() {
+            if let Some((start, end)) = current {
+                f(&section[start..end]);
+            }
+            current = Some((offset, end));
+        } else if !is_blank(line) {
+            if let Some((_, current_end)) = current.as_mut() {
+                *current_end = end;
+            }
+        }
+        offset += raw.len() + 1;
+    }
+    if let Some((start, end)) = current {
+        f(&section[start..end]);
+    }
+}
+
+/// Parses a single footer, `token: value` or `token #value`. The value may
+/// span multiple lines.
+fn parse_footer<'a>(
+    input: &'a str,
+    text: &'a str,
+    options: &ParseOptions,
+    errors: &mut Vec<ParseError>,
+) -> Option<(Footer<'a>, FooterSpans)> {
+    let (token, separator, value) = match split_footer(text, options) {
+        Some(footer) => footer,
+        None => {
+            errors.push(error(input, ParseErrorKind::MalformedFooter, text));
+            return None;
+        }
+    };
+    if options.strict && !is_valid_token(token) {
+        errors.push(error(input, ParseErrorKind::InvalidFooterToken, token));
+    }
+    let trimmed = value.trim_end();
+    if options.strict && value.len() != trimmed.len() {
+        let whitespace = &value[trimmed.len()..];
+        errors.push(error(
+            input,
+            ParseErrorKind::UnexpectedWhitespace,
+            whitespace,
+        ));
+    }
+    let value = if options.strict {
+        trimmed
+    } else {
+        value.trim()
+    };
+
+    let token_span = Span::of(input, token);
+    let separator_len = separator.as_str().len();
+    let spans = FooterSpans {
+        token: token_span,
+        separator: Span::new(token_span.end, token_span.end + separator_len),
+        value: Span::of(input, value),
+    };
+    Some((Footer::from(token, separator, value), spans))
+}
+
+/// Splits a footer into its token, separator and value.
+///
+/// Besides valid tokens, this also recognizes well-known tokens that wrongly
+/// use spaces instead of hyphens, like `Signed off by: value`, so they can be
+/// reported or accepted depending on the options.
+pub(crate) fn split_footer<'a>(
+    line: &'a str,
+    options: &ParseOptions,
+) -> Option<(&'a str, FooterSeparator<'a>, &'a str)> {
+    let token_len = if line.starts_with(BREAKING_CHANGE_TOKEN) {
+        BREAKING_CHANGE_TOKEN.len()
+    } else {
+        word_len(line)
+    };
+    if token_len == 0 {
+        return None;
+    }
+
+    let (token, rest) = line.split_at(token_len);
+    let separator = options
+        .footer_separators
+        .iter()
+        .find(|separator| !separator.is_empty() && rest.starts_with(separator.as_str()));
+    let (separator, value) = match separator {
+        Some(separator) => rest.split_at(separator.len()),
+        None => return split_spaced_footer(line, options),
+    };
+    if value.trim().is_empty() || link::is_link_separator(separator, value) {
+        return None;
+    }
+
+    Some((token, FooterSeparator::from(separator), value))
+}
+
+/// Splits a footer whose token is one of the [`SPACED_TOKENS`] with some of
+/// its hyphens written as spaces, ignoring case. Only the `:<space>` separator
+/// is accepted for such footers.
+fn split_spaced_footer<'a>(
+    line: &'a str,
+    options: &ParseOptions,
+) -> Option<(&'a str, FooterSeparator<'a>, &'a str)> {
+    if !options
+        .footer_separators
+        .iter()
+        .any(|separator| separator == SEPARATOR_COLON)
+    {
+        return None;
+    }
+
+    let mut token_len = 0;
+    for words in 1..=MAX_SPACED_TOKEN_WORDS {
+        let word = word_len(&line[token_len..]);
+        if word == 0 {
+            return None;
+        }
+        token_len += word;
+
+        let rest = &line[token_len..];
+        if rest.starts_with(SEPARATOR_COLON) {
+            let value = &rest[SEPARATOR_COLON.len()..];
+            let token = &line[..token_len];
+            if words == 1 || value.trim().is_empty() || !is_spaced_token(token) {
+                return None;
+            }
+            return Some((token, FooterSeparator::ColonSpace, value));
+        }
+        if !rest.starts_with(' ') {
+            return None;
+        }
+        token_len += 1;
+    }
+    None
+}
+
+/// Returns `true` if `token` is one of the [`SPACED_TOKENS`] with spaces in
+/// place of some of its hyphens, ignoring case.
+fn is_spaced_token(token: &str) -> bool {
+    SPACED_TOKENS.iter().any(|known| {
+        known.len() == token.len()
+            && known.bytes().zip(token.bytes()).all(|(known, byte)| {
+                byte.eq_ignore_ascii_case(&known) || (known == b'-' && byte == b' ')
+            })
+    })
+}
+
+/// Returns the length of the word at the start of `s`.
+fn word_len(s: &str) -> usize {
+    s.find(|c: char| !(c.is_alphanumeric() || c == '-'))
+        .unwrap_or(s.len())
+}
+
+/// Returns `true` if the footer token is a single word or `BREAKING CHANGE`.
+pub(crate) fn is_valid_token(token: &str) -> bool {
+    token == BREAKING_CHANGE_TOKEN || (!token.is_empty() && word_len(token) == token.len())
+}
+
+/// Returns `value` if no errors were recorded, otherwise the first error.
+pub(crate) fn first_error<T>(value: T, errors: Vec<ParseError>) -> Result<T, ParseError> {
+    match errors.into_iter().next() {
+        Some(error) => Err(error),
+        None => Ok(value),
+    }
+}
+
+/// Creates an error of the given kind located at `part`, a subslice of
+/// `input`.
+fn error(input: &str, kind: ParseErrorKind, part: &str) -> ParseError {
+    ParseError::at(kind, input, Span::of(input, part))
+}
+
+/// Splits off the first line of `s`, returning the remainder after the line
+/// feed, if any. The line does not include a trailing carriage return.
+pub(crate) fn split_first_line(s: &str) -> (&str, Option<&str>) {
+    match s.find('\n') {
+        Some(idx) => (strip_cr(&s[..idx]), Some(&s[idx + 1..])),
+        None => (strip_cr(s), None),
+    }
+}
+
+/// Removes the carriage return of a line that ended in `\r\n`.
+pub(crate) fn strip_cr(line: &str) -> &str {
+    if line.ends_with('\r') {
+        &line[..line.len() - 1]
+    } else {
+        line
+    }
+}
+
+/// Splits a scope into its parts, e.g. `api` and `cli` for `api,cli`.
+pub(crate) fn split_scopes(scope: Option<&str>) -> impl Iterator<Item = &str> {
+    scope
+        .unwrap_or("")
+        .split(SCOPE_SEPARATORS)
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+}
+
+/// Removes leading and trailing blank lines, keeping the whitespace of the
+/// remaining lines intact.
+fn trim_blank_lines(s: &str) -> &str {
+    let mut start = 0;
+    while let (line, Some(rest)) = split_first_line(&s[start..]) {
+        if !is_blank(line) {
+            break;
+        }
+        start = s.len() - rest.len();
+    }
+    let s = &s[start..];
+    let last = s.trim_end().len();
+    let end = s[last..].find('\n').map_or(s.len(), |idx| last + idx);
+    strip_cr(&s[..end])
+}
+
+/// Returns `true` if the line only contains whitespace.
+pub(crate) fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spaced_body_text_is_not_a_footer() {
+        let commit = Commit::parse("feat: x\n\nIn short: it works").unwrap();
+        assert_eq!(commit.body, Some("In short: it works"));
+        assert!(commit.footer.is_empty());
+
+        let commit = Commit::parse("docs: x\n\nThe fix is: simple").unwrap();
+        assert_eq!(commit.body, Some("The fix is: simple"));
+        assert!(commit.footer.is_empty());
+    }
+
+    #[test]
+    fn spaced_known_token_is_rejected_in_strict_mode() {
+        let error = Commit::parse("feat: x\n\nSigned off by: Jane").unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::InvalidFooterToken);
+
+        let commit =
+            Commit::parse_with("feat: x\n\nsigned off by: Jane", &ParseOptions::lenient()).unwrap();
+        assert_eq!(commit.footer[0].token, "signed off by");
+        assert_eq!(commit.body, None);
+    }
+
+    fn spaced(line: &str) -> Option<(&str, &str)> {
+        split_spaced_footer(line, &ParseOptions::default()).map(|(token, separator, value)| {
+            assert_eq!(separator, FooterSeparator::ColonSpace);
+            (token, value)
+        })
+    }
+
+    #[test]
+    fn split_spaced_footer_accepts_known_tokens() {
+        assert_eq!(
+            spaced("Signed off by: Jane"),
+            Some(("Signed off by", "Jane"))
+        );
+        assert_eq!(
+            spaced("co AUTHORED by: Bob"),
+            Some(("co AUTHORED by", "Bob"))
+        );
+        assert_eq!(
+            spaced("Signed-off by: Jane"),
+            Some(("Signed-off by", "Jane"))
+        );
+        assert_eq!(spaced("Acked by: a: b"), Some(("Acked by", "a: b")));
+    }
+
+    #[test]
+    fn split_spaced_footer_rejects_other_lines() {
+        for line in &[
+            "Refs: #1",
+            "Signed-off-by: Jane",
+            "In short: it works",
+            "The fix is: simple",
+            "Signed off by us: Jane",
+            "Reviewed by:Jane",
+            "Reviewed by: ",
+            "Reviewed by #12",
+            "Reviewed  by: Jane",
+            " Reviewed by: Jane",
+            "",
+        ] {
+            assert_eq!(spaced(line), None, "{:?}", line);
+        }
+
+        let options = ParseOptions {
+            footer_separators: vec![" #".to_string()],
+            ..ParseOptions::default()
+        };
+        assert_eq!(split_spaced_footer("Reviewed by: Jane", &options), None);
+    }
+
+    fn footer_section<'a>(content: &'a str, options: &ParseOptions) -> &'a str {
+        &content[find_footer_section(content, options)..]
+    }
+
+    #[test]
+    fn find_footer_section_finds_the_trailing_footers() {
+        let options = ParseOptions::default();
+        let section = |content| footer_section(content, &options);
+        assert_eq!(section("A body.\n\nRefs: #1"), "Refs: #1");
+        assert_eq!(section("Refs: #1\n\nCloses #2"), "Refs: #1\n\nCloses #2");
+        assert_eq!(section("A body.\r\n\r\nRefs: #1\r\n"), "Refs: #1\r\n");
+        assert_eq!(
+            section("Refs: #1\ncontinued\n\nAcked-by: Jane"),
+            "Refs: #1\ncontinued\n\nAcked-by: Jane"
+        );
+        assert_eq!(section("Note: a\n\nno footer"), "");
+        assert_eq!(section("A body\nRefs: #1"), "");
+        assert_eq!(section(""), "");
+    }
+
+    #[test]
+    fn find_footer_section_continues_breaking_change_notes() {
+        let note = "A body.\n\nBREAKING CHANGE: a\n\nmore text\n\nRefs: #1";
+        assert_eq!(
+            footer_section(note, &ParseOptions::default()),
+            "BREAKING CHANGE: a\n\nmore text\n\nRefs: #1"
+        );
+        let alias = "BREAKING-CHANGE: a\n\nmore text";
+        assert_eq!(footer_section(alias, &ParseOptions::default()), alias);
+
+        let angular = ParseOptions {
+            convention: Convention::Angular,
+            ..ParseOptions::default()
+        };
+        assert_eq!(
+            footer_section("BREAKING CHANGE: a\n\nmore text", &angular),
+            "BREAKING CHANGE: a\n\nmore text"
+        );
+        assert_eq!(footer_section(alias, &angular), "");
+    }
+}
@@ -0,0 +1,62 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A person with a name and an email address, as written in footers like
+/// `Signed-off-by: Jane Doe <jane@example.com>`.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::Person;
+///
+/// let person = Person::parse("Jane Doe <jane@example.com>").unwrap();
+/// assert_eq!(person.name, "Jane Doe");
+/// assert_eq!(person.email, "jane@example.com");
+/// ```
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Person<'a> {
+    /// The email address, without the angle brackets.
+    pub email: &'a str,
+    /// The name, which may be empty.
+    pub name: &'a str,
+}
+
+impl<'a> Person<'a> {
+    /// Creates a person with the given values.
+    pub const fn from(name: &'a str, email: &'a str) -> Self {
+        Self { email, name }
+    }
+
+    /// Parses `Name <email>`.
+    ///
+    /// Returns `None` if there is no email address in angle brackets at the
+    /// end of `value`.
+    pub fn parse(value: &'a str) -> Option<Self> {
+        let value = value.trim();
+        if !value.ends_with('>') {
+            return None;
+        }
+        let start = value.rfind('<')?;
+        let email = value[start + 1..value.len() - 1].trim();
+        if email.is_empty() || email.contains(char::is_whitespace) {
+            return None;
+        }
+        Some(Self {
+            email,
+            name: value[..start].trim(),
+        })
+    }
+}
+
+impl fmt::Display for Person<'_> {
+    /// Formats the person as `Name <email>`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.name.is_empty() {
+            write!(f, "<{}>", self.email)
+        } else {
+            write!(f, "{} <{}>", self.name, self.email)
+        }
+    }
+}
@@ -0,0 +1,317 @@
+use crate::{link, Commit, FooterSeparator, Span};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The keywords that close an issue when they precede a reference.
+const ACTIONS: &[&str] = &[
+    "close", "closes", "closed", "fix", "fixes", "fixed", "resolve", "resolves", "resolved",
+];
+
+/// A reference to an issue or pull request, like `#123`,
+/// `owner/repo#123` or `https://github.com/owner/repo/issues/123`.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::Commit;
+///
+/// let commit = Commit::parse("fix: handle empty input\n\nCloses octocat/parser#7").unwrap();
+/// let reference = &commit.references()[0];
+/// assert_eq!(reference.action, Some("Closes"));
+/// assert_eq!(reference.owner, Some("octocat"));
+/// assert_eq!(reference.repo, Some("parser"));
+/// assert_eq!(reference.number, 7);
+///
+/// // Any character may come right before a reference.
+/// let commit = Commit::parse("fix: handle ✨ #12\n\nSee “#13”").unwrap();
+/// let numbers: Vec<_> = commit.references().iter().map(|r| r.number).collect();
+/// assert_eq!(numbers, [12, 13]);
+/// ```
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Reference<'a> {
+    /// The keyword in front of the reference that closes the issue, like
+    /// `Fixes` or `closes`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub action: Option<&'a str>,
+    /// The number of the issue or pull request.
+    pub number: u64,
+    /// The owner of the repository, if the reference is not to the same one.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub owner: Option<&'a str>,
+    /// The name of the repository, if the reference is not to the same one.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub repo: Option<&'a str>,
+}
+
+/// What a commit does to an issue or pull request it refers to.
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum IssueAction {
+    /// The issue is only referred to, as with `Refs: #1` or a mention in
+    /// the body.
+    Refs,
+    /// The issue is closed, as with `Closes #1`.
+    Closes,
+    /// The issue is closed as fixed, as with `Fixes #1`.
+    Fixes,
+    /// The issue is closed as resolved, as with `Resolves #1`.
+    Resolves,
+}
+
+impl IssueAction {
+    /// Returns the action of a closing keyword like `fixed`, or
+    /// [`IssueAction::Refs`] without one.
+    fn from_keyword(keyword: Option<&str>) -> Self {
+        let keyword = match keyword {
+            Some(keyword) => keyword.to_ascii_lowercase(),
+            None => return IssueAction::Refs,
+        };
+        if keyword.starts_with("close") {
+            IssueAction::Closes
+        } else if keyword.starts_with("fix") {
+            IssueAction::Fixes
+        } else {
+            IssueAction::Resolves
+        }
+    }
+
+    /// Returns `true` if the action closes the issue.
+    pub fn is_closing(self) -> bool {
+        self != IssueAction::Refs
+    }
+}
+
+/// A distinct issue or pull request that a commit refers to, with what the
+/// commit does to it.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{Commit, IssueAction};
+///
+/// let commit = Commit::parse("fix: handle empty input (#7)\n\nFixes #7\nRefs: #3").unwrap();
+/// let refs = commit.issue_refs();
+/// assert_eq!(refs.len(), 2);
+/// assert_eq!((refs[0].number, refs[0].action), (7, IssueAction::Fixes));
+/// assert_eq!((refs[1].number, refs[1].action), (3, IssueAction::Refs));
+/// ```
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct IssueRef<'a> {
+    /// What the commit does to the issue.
+    pub action: IssueAction,
+    /// The number of the issue or pull request.
+    pub number: u64,
+    /// The owner of the repository, if the reference is not to the same one.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub owner: Option<&'a str>,
+    /// The name of the repository, if the reference is not to the same one.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub repo: Option<&'a str>,
+}
+
+/// Returns the distinct issues referred to by a commit, in the order they
+/// are first referred to.
+///
+/// If an issue is referred to multiple times, the closing action wins.
+pub(crate) fn issue_refs<'a>(commit: &Commit<'a>) -> Vec<IssueRef<'a>> {
+    let mut refs: Vec<IssueRef<'a>> = Vec::new();
+    for reference in references(commit) {
+        let action = IssueAction::from_keyword(reference.action);
+        let existing = refs.iter_mut().find(|existing| {
+            existing.number == reference.number
+                && existing.owner == reference.owner
+                && existing.repo == reference.repo
+        });
+        match existing {
+            Some(existing) => {
+                if !existing.action.is_closing() {
+                    existing.action = action;
+                }
+            }
+            None => refs.push(IssueRef {
+                action,
+                number: reference.number,
+                owner: reference.owner,
+                repo: reference.repo,
+            }),
+        }
+    }
+    refs
+}
+
+/// Returns the references in the description, body and footers of a commit,
+/// in this order.
+pub(crate) fn references<'a>(commit: &Commit<'a>) -> Vec<Reference<'a>> {
+    let mut references = scan(commit.desc);
+    if let Some(body) = commit.body {
+        references.extend(scan(body));
+    }
+    for footer in &commit.footer {
+        let action = if is_action(footer.token) {
+            Some(footer.token)
+        } else {
+            None
+        };
+        let start = references.len();
+        if footer.separator == FooterSeparator::SpaceHashTag {
+            // The `#` is part of the separator, so the value starts with the
+            // number itself.
+            let digits = digits_len(footer.value);
+            if digits > 0 && !footer.value[digits..].starts_with(is_word_char) {
+                if let Ok(number) = footer.value[..digits].parse() {
+                    references.push(Reference {
+                        action: None,
+                        number,
+                        owner: None,
+                        repo: None,
+                    });
+                }
+            }
+        }
+        references.extend(scan(footer.value));
+        for reference in &mut references[start..] {
+            reference.action = reference.action.or(action);
+        }
+    }
+    references
+}
+
+/// Returns the references in a text.
+fn scan(text: &str) -> Vec<Reference<'_>> {
+    locate(text)
+        .into_iter()
+        .map(|(_, reference)| reference)
+        .collect()
+}
+
+/// Returns the references in a text together with the text they cover.
+///
+/// The span of an `owner/repo#123` reference includes the repository, but
+/// not a closing keyword in front of it.
+pub(crate) fn locate(text: &str) -> Vec<(Span, Reference<'_>)> {
+    let mut references = Vec::new();
+    let mut idx = 0;
+    while idx < text.len() {
+        let rest = &text[idx..];
+        let is_url = rest.starts_with("https://") || rest.starts_with("http://");
+        let found = if is_url {
+            scan_url(rest)
+        } else if rest.starts_with('#') {
+            scan_hash(text, idx)
+        } else {
+            None
+        };
+        match found {
+            Some((reference, len)) => {
+                let prefix_len = owner_repo_len(&text[..idx]);
+                let start = if is_url { idx } else { idx - prefix_len };
+                let reference = Reference {
+                    action: preceding_action(text, idx - prefix_len),
+                    ..reference
+                };
+                references.push((Span::new(start, idx + len), reference));
+                idx += len;
+            }
+            None => idx += rest.chars().next().map_or(1, char::len_utf8),
+        }
+    }
+    references
+}
+
+/// Scans a `#123` or `owner/repo#123` reference whose `#` is at `idx`.
+///
+/// Returns the reference and the length of the text after `idx` it covers.
+fn scan_hash(text: &str, idx: usize) -> Option<(Reference<'_>, usize)> {
+    let digits = digits_len(&text[idx + 1..]);
+    let end = idx + 1 + digits;
+    if digits == 0 || text[end..].starts_with(is_word_char) {
+        return None;
+    }
+    let number = text[idx + 1..end].parse().ok()?;
+
+    let prefix_len = owner_repo_len(&text[..idx]);
+    let prefix = &text[idx - prefix_len..idx];
+    let (owner, repo) = if prefix.is_empty() {
+        (None, None)
+    } else {
+        let mut parts = prefix.splitn(2, '/');
+        match (parts.next(), parts.next()) {
+            (Some(owner), Some(repo)) if !owner.is_empty() && !repo.is_empty() => {
+                (Some(owner), Some(repo))
+            }
+            _ => return None,
+        }
+    };
+    if text[..idx - prefix_len].ends_with(is_word_char) {
+        return None;
+    }
+    let reference = Reference {
+        action: None,
+        number,
+        owner,
+        repo,
+    };
+    Some((reference, end - idx))
+}
+
+/// Scans a URL of an issue or pull request, like
+/// `https://github.com/owner/repo/pull/123`.
+fn scan_url(text: &str) -> Option<(Reference<'_>, usize)> {
+    let url = &text[..link::link_len(text)];
+    let mut segments = url.splitn(2, "://").nth(1)?.split('/').skip(1);
+    let owner = segments.next().filter(|owner| !owner.is_empty())?;
+    let repo = segments.next().filter(|repo| !repo.is_empty())?;
+    let mut kind = segments.next()?;
+    if kind == "-" {
+        kind = segments.next()?;
+    }
+    if !["issues", "pull", "pulls", "merge_requests"].contains(&kind) {
+        return None;
+    }
+    let number = segments.next()?.parse().ok()?;
+    let reference = Reference {
+        action: None,
+        number,
+        owner: Some(owner),
+        repo: Some(repo),
+    };
+    Some((reference, url.len()))
+}
+
+/// Returns the closing keyword in front of the reference starting at `idx`.
+fn preceding_action(text: &str, idx: usize) -> Option<&str> {
+    let before = text[..idx].trim_end();
+    let word = &before[before.trim_end_matches(char::is_alphanumeric).len()..];
+    if is_action(word) {
+        Some(word)
+    } else {
+        None
+    }
+}
+
+/// Returns `true` if `word` is one of the closing keywords.
+fn is_action(word: &str) -> bool {
+    ACTIONS
+        .iter()
+        .any(|action| action.eq_ignore_ascii_case(word))
+}
+
+/// Returns the length of the `owner/repo` in front of a `#`.
+fn owner_repo_len(before: &str) -> usize {
+    before.len()
+        - before
+            .trim_end_matches(|c: char| is_word_char(c) || c == '/' || c == '.')
+            .len()
+}
+
+/// Returns the number of ASCII digits at the start of `s`.
+fn digits_len(s: &str) -> usize {
+    s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len())
+}
+
+/// Returns `true` for characters that may be part of a name.
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_'
+}
@@ -0,0 +1,109 @@
+use crate::{parser, CaseSensitivity, Commit, Header, ParseOptions, REFS_TOKEN};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The sentence that `git revert` puts into the body of the message.
+const REVERTS_COMMIT: &str = "This reverts commit ";
+
+/// A commit that reverts one or more earlier commits.
+///
+/// Both the conventional form, `revert: feat(x): add thing`, and the message
+/// generated by `git revert`, `Revert "feat(x): add thing"`, are recognized.
+/// The hashes of the reverted commits are taken from the
+/// `This reverts commit <sha>.` lines of the body and from a `Refs` footer,
+/// as shown in the specification.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{ParseOptions, Revert};
+///
+/// let message = "revert: feat(parser): add a parser\n\nThis reverts commit 1a2b3c4d.";
+/// let revert = Revert::parse(message, &ParseOptions::default()).unwrap();
+/// assert_eq!(revert.header.unwrap().scope, Some("parser"));
+/// assert_eq!(revert.commits, ["1a2b3c4d"]);
+/// ```
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Revert<'a> {
+    /// The hashes of the reverted commits.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub commits: Vec<&'a str>,
+    /// The header of the reverted commit, if it is a conventional one.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub header: Option<Header<'a>>,
+    /// The first line of the reverted commit, as written.
+    pub subject: &'a str,
+}
+
+impl<'a> Revert<'a> {
+    /// Parses a revert commit message.
+    ///
+    /// The options are used to parse both the message and the header of the
+    /// reverted commit. Returns `None` if the message is not a revert.
+    pub fn parse(input: &'a str, options: &ParseOptions) -> Option<Self> {
+        if let Ok(commit) = Commit::parse_with(input, options) {
+            return Self::from_commit(&commit, options);
+        }
+
+        let (header, body) = parser::split_first_line(input);
+        let header = header.trim_end();
+        if !header.starts_with("Revert \"") || !header.ends_with('"') || header.len() < 9 {
+            return None;
+        }
+        let subject = &header["Revert \"".len()..header.len() - 1];
+        Some(Self {
+            commits: body.map_or_else(Vec::new, reverted_commits),
+            header: parse_subject(subject, options),
+            subject,
+        })
+    }
+
+    /// Returns the revert described by a commit of the `revert` type.
+    ///
+    /// Returns `None` for commits of other types.
+    pub fn from_commit(commit: &Commit<'a>, options: &ParseOptions) -> Option<Self> {
+        if !commit.ty.eq_ignore_ascii_case("revert") {
+            return None;
+        }
+
+        let mut commits = commit.body.map_or_else(Vec::new, reverted_commits);
+        if let Some(refs) = commit.find_footer(REFS_TOKEN, CaseSensitivity::Insensitive) {
+            let hashes: Vec<_> = refs.value.split(',').map(str::trim).collect();
+            if hashes.iter().all(|hash| is_hash(hash)) {
+                commits.extend(hashes);
+            }
+        }
+        Some(Self {
+            commits,
+            header: parse_subject(commit.desc, options),
+            subject: commit.desc,
+        })
+    }
+}
+
+/// Parses the subject of the reverted commit, if it is conventional.
+fn parse_subject<'a>(subject: &'a str, options: &ParseOptions) -> Option<Header<'a>> {
+    let mut errors = Vec::new();
+    let (header, _) = parser::parse_header(subject, options, &mut errors);
+    if errors.is_empty() {
+        Some(header)
+    } else {
+        None
+    }
+}
+
+/// Returns the hashes of all `This reverts commit <sha>.` lines.
+fn reverted_commits(body: &str) -> Vec<&str> {
+    body.lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with(REVERTS_COMMIT))
+        .map(|line| line[REVERTS_COMMIT.len()..].trim_end_matches('.'))
+        .filter(|hash| is_hash(hash))
+        .collect()
+}
+
+/// Returns `true` if `s` looks like an abbreviated or full commit hash.
+fn is_hash(s: &str) -> bool {
+    s.len() >= 4 && s.len() <= 64 && s.chars().all(|c| c.is_ascii_hexdigit())
+}
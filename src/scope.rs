@@ -0,0 +1,95 @@
+use crate::{ParseError, ParseErrorKind};
+use std::{borrow::Cow, convert::TryFrom, fmt};
+
+/// A scope that is known to be valid.
+///
+/// The specification requires the scope to be a noun in parentheses, so it
+/// must not be empty and must not contain parentheses or line breaks. Valid
+/// scopes also have no whitespace around them. Use [`Scope::normalize`] to
+/// accept scopes from user input, which trims and lowercases them first.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{Commit, Scope};
+///
+/// assert!(Scope::new("api").is_ok());
+/// assert!(Scope::new("api)").is_err());
+/// assert!(Scope::new(" api ").is_err());
+///
+/// let scope = Scope::normalize(" API ").unwrap();
+/// assert_eq!(scope.as_str(), "api");
+///
+/// let commit = Commit::new().with_type("feat").with_desc("add an endpoint").with_scope(scope.as_str());
+/// assert_eq!(commit.to_string(), "feat(api): add an endpoint");
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Scope<'a>(Cow<'a, str>);
+
+impl<'a> Scope<'a> {
+    /// Checks that `scope` is valid, without changing it.
+    ///
+    /// Fails with [`ParseErrorKind::InvalidScope`] for empty scopes and those
+    /// with parentheses or line breaks, and with
+    /// [`ParseErrorKind::UnexpectedWhitespace`] for those with whitespace
+    /// around them.
+    pub fn new(scope: &'a str) -> Result<Self, ParseError> {
+        check(scope)?;
+        Ok(Self(Cow::Borrowed(scope)))
+    }
+
+    /// Trims and lowercases `scope`, then checks that it is valid.
+    ///
+    /// The scope is only copied if it has uppercase letters.
+    pub fn normalize(scope: &'a str) -> Result<Self, ParseError> {
+        let scope = scope.trim();
+        check(scope)?;
+        Ok(Self(if scope.chars().any(char::is_uppercase) {
+            Cow::Owned(scope.to_lowercase())
+        } else {
+            Cow::Borrowed(scope)
+        }))
+    }
+
+    /// Returns the scope.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the scope, borrowed from the input if it was not changed.
+    pub fn into_inner(self) -> Cow<'a, str> {
+        self.0
+    }
+}
+
+/// Fails if `scope` is not a valid scope.
+fn check(scope: &str) -> Result<(), ParseError> {
+    if scope.is_empty() || scope.contains(|c| c == '(' || c == ')' || c == '\n' || c == '\r') {
+        return Err(ParseError::new(ParseErrorKind::InvalidScope));
+    }
+    if scope.trim().len() != scope.len() {
+        return Err(ParseError::new(ParseErrorKind::UnexpectedWhitespace));
+    }
+    Ok(())
+}
+
+impl fmt::Display for Scope<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl AsRef<str> for Scope<'_> {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Scope<'a> {
+    type Error = ParseError;
+
+    /// Checks that `scope` is valid, see [`Scope::new`].
+    fn try_from(scope: &'a str) -> Result<Self, Self::Error> {
+        Self::new(scope)
+    }
+}
@@ -0,0 +1,42 @@
+use crate::Commit;
+
+/// Returns `true` if the two texts have the same words, ignoring how much
+/// and which whitespace separates them.
+fn eq_words(a: &str, b: &str) -> bool {
+    a.split_whitespace().eq(b.split_whitespace())
+}
+
+/// Returns the footers of a commit that do not mark it as breaking, as
+/// pairs of the lowercase token and the value with collapsed whitespace,
+/// sorted.
+fn semantic_footers(commit: &Commit<'_>) -> Vec<(String, String)> {
+    let mut footers: Vec<_> = commit
+        .footer
+        .iter()
+        .filter(|footer| !footer.is_breaking_change())
+        .map(|footer| {
+            (
+                footer.token.to_ascii_lowercase(),
+                footer
+                    .value
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            )
+        })
+        .collect();
+    footers.sort();
+    footers
+}
+
+/// Returns `true` if the two commits mean the same, as described by
+/// [`Commit::eq_semantic`].
+pub(crate) fn eq_semantic(a: &Commit<'_>, b: &Commit<'_>) -> bool {
+    a.ty.eq_ignore_ascii_case(b.ty)
+        && a.scope == b.scope
+        && eq_words(a.desc, b.desc)
+        && eq_words(a.body.unwrap_or(""), b.body.unwrap_or(""))
+        && a.is_breaking_change == b.is_breaking_change
+        && a.autosquash == b.autosquash
+        && semantic_footers(a) == semantic_footers(b)
+}
@@ -0,0 +1,79 @@
+use crate::FooterVec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+/// A byte range inside of a parsed commit message.
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Span {
+    /// The offset of the first byte.
+    pub start: usize,
+    /// The offset after the last byte.
+    pub end: usize,
+}
+
+impl Span {
+    /// Creates a span covering `start..end`.
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// Creates the span of `inner`, which has to be a subslice of `outer`.
+    pub(crate) fn of(outer: &str, inner: &str) -> Self {
+        let start = inner.as_ptr() as usize - outer.as_ptr() as usize;
+        Self::new(start, start + inner.len())
+    }
+
+    /// Returns the length of the span in bytes.
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns `true` if the span does not cover any bytes.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+impl From<Span> for Range<usize> {
+    fn from(span: Span) -> Self {
+        span.start..span.end
+    }
+}
+
+/// The locations of the components of a parsed commit message.
+///
+/// The fields mirror the ones of [`Commit`](crate::Commit).
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct CommitSpans {
+    /// The span of the body.
+    pub body: Option<Span>,
+    /// The span of the `!` breaking change marker in the header.
+    pub breaking_marker: Option<Span>,
+    /// The span of the description.
+    pub desc: Span,
+    /// The spans of the footers, in the same order as the footers.
+    pub footer: FooterVec<FooterSpans>,
+    /// The span of the gitmoji in front of the type.
+    pub gitmoji: Option<Span>,
+    /// The span of the whole header line.
+    pub header: Span,
+    /// The span of the scope, without the parentheses.
+    pub scope: Option<Span>,
+    /// The span of the type.
+    pub ty: Span,
+}
+
+/// The locations of the components of a parsed footer.
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FooterSpans {
+    /// The span of the token.
+    pub token: Span,
+    /// The span of the separator.
+    pub separator: Span,
+    /// The span of the value.
+    pub value: Span,
+}
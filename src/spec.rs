@@ -0,0 +1,62 @@
+/// A revision of the Conventional Commits specification.
+///
+/// The revisions differ in how breaking changes are marked. Parsing with an
+/// older revision reproduces how tools interpreted histories at the time:
+///
+/// - the `!` marker in the header was introduced in `1.0.0-beta.4`,
+/// - before that, a paragraph of the body could start with `BREAKING CHANGE: `
+///   as well,
+/// - the `BREAKING-CHANGE` synonym was introduced in `1.0.0`.
+///
+/// The revisions are ordered by their release.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SpecVersion {
+    /// Version `1.0.0-beta.1`.
+    V1_0_0Beta1,
+    /// Version `1.0.0-beta.2`.
+    V1_0_0Beta2,
+    /// Version `1.0.0-beta.3`.
+    V1_0_0Beta3,
+    /// Version `1.0.0-beta.4`.
+    V1_0_0Beta4,
+    /// Version `1.0.0`.
+    V1_0_0,
+}
+
+impl SpecVersion {
+    /// Returns the version number, like `1.0.0-beta.4`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SpecVersion::V1_0_0Beta1 => "1.0.0-beta.1",
+            SpecVersion::V1_0_0Beta2 => "1.0.0-beta.2",
+            SpecVersion::V1_0_0Beta3 => "1.0.0-beta.3",
+            SpecVersion::V1_0_0Beta4 => "1.0.0-beta.4",
+            SpecVersion::V1_0_0 => "1.0.0",
+        }
+    }
+
+    /// Returns `true` if the `!` breaking change marker is part of the
+    /// revision.
+    pub fn allows_breaking_marker(self) -> bool {
+        self >= SpecVersion::V1_0_0Beta4
+    }
+
+    /// Returns `true` if `BREAKING-CHANGE` marks breaking changes like
+    /// `BREAKING CHANGE` does.
+    pub fn allows_breaking_change_alias(self) -> bool {
+        self >= SpecVersion::V1_0_0
+    }
+
+    /// Returns `true` if a paragraph of the body starting with
+    /// `BREAKING CHANGE: ` marks a breaking change.
+    pub fn allows_breaking_change_in_body(self) -> bool {
+        self < SpecVersion::V1_0_0Beta4
+    }
+}
+
+impl Default for SpecVersion {
+    /// Returns the default SpecVersion, the V1_0_0.
+    fn default() -> Self {
+        SpecVersion::V1_0_0
+    }
+}
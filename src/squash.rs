@@ -0,0 +1,61 @@
+use crate::{parser, Commit, ParseOptions};
+
+/// The prefix that GitHub puts in front of each squashed commit.
+const BULLET: &str = "* ";
+
+/// Splits the message of a GitHub squash merge into the commits it consists
+/// of.
+///
+/// When squashing, GitHub lists the messages of all merged commits in the
+/// body, each of them starting with a `* ` bullet. Every bullet whose text
+/// is a valid header starts a new commit that extends until the next one.
+/// A line made out of dashes, which GitHub puts in front of the
+/// `Co-authored-by` footers, ends the last commit.
+///
+/// Commits whose body or footers cannot be parsed are returned with their
+/// header only. Returns an empty list if the message does not contain any
+/// squashed commits.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{split_squash, ParseOptions};
+///
+/// let message = "Add a parser (#12)\n\n* feat: add a parser\n\n* fix(parser): handle empty input";
+/// let commits = split_squash(message, &ParseOptions::default());
+/// assert_eq!(commits.len(), 2);
+/// assert_eq!(commits[1].scope, Some("parser"));
+/// ```
+pub fn split_squash<'a>(input: &'a str, options: &ParseOptions) -> Vec<Commit<'a>> {
+    let mut starts = Vec::new();
+    let mut end = input.len();
+    let mut offset = 0;
+    for line in input.split('\n') {
+        let text = parser::strip_cr(line);
+        if offset > 0 && text.starts_with(BULLET) {
+            let mut errors = Vec::new();
+            parser::parse_header(&text[BULLET.len()..], options, &mut errors);
+            if errors.is_empty() {
+                starts.push(offset + BULLET.len());
+            }
+        } else if !starts.is_empty() && text.len() >= 3 && text.chars().all(|c| c == '-') {
+            end = offset;
+            break;
+        }
+        offset += line.len() + 1;
+    }
+
+    let mut commits = Vec::with_capacity(starts.len());
+    for (idx, &start) in starts.iter().enumerate() {
+        let section_end = starts.get(idx + 1).map_or(end, |next| next - BULLET.len());
+        let section = input[start..section_end].trim_end();
+        let commit = Commit::parse_with(section, options).unwrap_or_else(|_| {
+            let mut errors = Vec::new();
+            let (header, _) =
+                parser::parse_header(parser::split_first_line(section).0, options, &mut errors);
+            Commit::from_header(header)
+        });
+        commits.push(commit);
+    }
+    commits
+}
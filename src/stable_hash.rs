@@ -0,0 +1,75 @@
+use crate::{Autosquash, Commit};
+
+/// The offset basis of the 64-bit FNV-1a hash.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// The prime of the 64-bit FNV-1a hash.
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// A 64-bit FNV-1a hasher, which does not depend on the platform or the
+/// version of the standard library.
+struct Fnv(u64);
+
+impl Fnv {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.write(&n.to_le_bytes());
+    }
+
+    /// Writes a string prefixed with its length, so that the boundaries
+    /// between strings are part of the hash.
+    fn write_str(&mut self, s: &str) {
+        self.write_u64(s.len() as u64);
+        self.write(s.as_bytes());
+    }
+
+    fn write_opt_str(&mut self, s: Option<&str>) {
+        match s {
+            Some(s) => {
+                self.write(&[1]);
+                self.write_str(s);
+            }
+            None => self.write(&[0]),
+        }
+    }
+}
+
+/// Returns the stable hash of a commit.
+///
+/// The type, scope, description, body, breaking change flag and footers are
+/// always hashed, in this order. Components that were added later, like the
+/// autosquash prefix and the gitmoji, are only hashed if they are set, after
+/// a tag that identifies them, so that commits without them keep their hash.
+pub(crate) fn stable_hash(commit: &Commit<'_>) -> u64 {
+    let mut hasher = Fnv(FNV_OFFSET_BASIS);
+    hasher.write_str(commit.ty);
+    hasher.write_opt_str(commit.scope);
+    hasher.write_str(commit.desc);
+    hasher.write_opt_str(commit.body);
+    hasher.write(&[commit.is_breaking_change as u8]);
+    hasher.write_u64(commit.footer.len() as u64);
+    for footer in &commit.footer {
+        hasher.write_str(footer.token);
+        hasher.write_str(footer.separator.as_str());
+        hasher.write_str(footer.value);
+    }
+
+    if let Some(autosquash) = commit.autosquash {
+        hasher.write(b"a");
+        hasher.write(&[match autosquash {
+            Autosquash::Fixup => 0,
+            Autosquash::Squash => 1,
+            Autosquash::Amend => 2,
+        }]);
+    }
+    if let Some(gitmoji) = commit.gitmoji {
+        hasher.write(b"g");
+        hasher.write_str(gitmoji);
+    }
+    hasher.0
+}
@@ -0,0 +1,55 @@
+use crate::{convention, Convention, Rules, COMMENT_CHAR};
+
+/// Returns a message template that explains the rules in comments.
+pub(crate) fn template(rules: &Rules) -> String {
+    let mut lines = vec!["type(scope): description".to_string(), String::new()];
+
+    let types = match (&rules.types, rules.options.convention) {
+        (Some(types), _) => Some(types.join(", ")),
+        (None, Convention::Angular) => Some(convention::ANGULAR_TYPES.join(", ")),
+        (None, Convention::Conventional) => None,
+    };
+    if let Some(types) = types {
+        lines.push(format!("Allowed types: {}", types));
+    }
+    if let Some(scopes) = &rules.scopes {
+        lines.push(format!("Allowed scopes: {}", scopes.join(", ")));
+    }
+    if rules.require_scope {
+        lines.push("The scope is required.".to_string());
+    }
+    if let Some(max) = rules.max_header_length {
+        lines.push(format!(
+            "The header must not be longer than {} characters.",
+            max
+        ));
+    }
+    if rules.forbid_trailing_period {
+        lines.push("The description must not end with a period.".to_string());
+    }
+    if let Some(max) = rules.max_body_line_length {
+        lines.push(format!("Wrap the body at {} characters.", max));
+    }
+
+    lines.push(String::new());
+    let allows_marker = rules.options.convention == Convention::Conventional
+        && rules.options.spec_version.allows_breaking_marker();
+    if allows_marker {
+        lines.push("Mark breaking changes with a `!` in front of the colon or".to_string());
+        lines.push("a `BREAKING CHANGE: <description>` footer.".to_string());
+    } else {
+        lines.push("Mark breaking changes with a".to_string());
+        lines.push("`BREAKING CHANGE: <description>` footer.".to_string());
+    }
+
+    let mut template = String::from("\n");
+    for line in lines {
+        template.push(COMMENT_CHAR);
+        if !line.is_empty() {
+            template.push(' ');
+            template.push_str(&line);
+        }
+        template.push('\n');
+    }
+    template
+}
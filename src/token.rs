@@ -0,0 +1,109 @@
+use crate::{BREAKING_CHANGE_TOKEN, BREAKING_CHANGE_TOKEN_ALIAS};
+use std::fmt;
+
+/// The token of the footer that `git commit --signoff` adds.
+pub const SIGNED_OFF_BY_TOKEN: &str = "Signed-off-by";
+
+/// The token of the footers that credit co-authors, as spelled by GitHub.
+pub const CO_AUTHORED_BY_TOKEN: &str = "Co-authored-by";
+
+/// The token of the footers that name reviewers.
+pub const REVIEWED_BY_TOKEN: &str = "Reviewed-by";
+
+/// The token of the footers that name people who acknowledged a change.
+pub const ACKED_BY_TOKEN: &str = "Acked-by";
+
+/// The token of the footers that refer to issues or commits, as used in the
+/// specification.
+pub const REFS_TOKEN: &str = "Refs";
+
+/// The token of the footers that close issues.
+pub const CLOSES_TOKEN: &str = "Closes";
+
+/// The token of the footers that close issues by fixing them.
+pub const FIXES_TOKEN: &str = "Fixes";
+
+/// The token of a footer, with variants for the well-known tokens.
+///
+/// Apart from `BREAKING CHANGE`, which has to be uppercase, the well-known
+/// tokens are recognized regardless of their case. Both `BREAKING CHANGE`
+/// and `BREAKING-CHANGE` are [`FooterToken::BreakingChange`].
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{Commit, FooterToken};
+///
+/// let commit = Commit::parse("feat: pair up\n\nCo-Authored-By: Jane <jane@example.com>").unwrap();
+/// assert_eq!(commit.footer[0].footer_token(), FooterToken::CoAuthoredBy);
+/// assert_eq!(FooterToken::CoAuthoredBy.as_str(), "Co-authored-by");
+/// assert_eq!(FooterToken::from("Tested-by"), FooterToken::Other("Tested-by"));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum FooterToken<'a> {
+    /// `BREAKING CHANGE` or `BREAKING-CHANGE`.
+    BreakingChange,
+    /// `Signed-off-by`.
+    SignedOffBy,
+    /// `Co-authored-by`.
+    CoAuthoredBy,
+    /// `Reviewed-by`.
+    ReviewedBy,
+    /// `Acked-by`.
+    AckedBy,
+    /// `Refs`.
+    Refs,
+    /// `Closes`.
+    Closes,
+    /// `Fixes`.
+    Fixes,
+    /// Any other token, as written in the message.
+    Other(&'a str),
+}
+
+impl<'a> FooterToken<'a> {
+    /// Returns the canonical spelling of the token.
+    ///
+    /// Other tokens are returned as written in the message.
+    pub fn as_str(&self) -> &'a str {
+        match self {
+            FooterToken::BreakingChange => BREAKING_CHANGE_TOKEN,
+            FooterToken::SignedOffBy => SIGNED_OFF_BY_TOKEN,
+            FooterToken::CoAuthoredBy => CO_AUTHORED_BY_TOKEN,
+            FooterToken::ReviewedBy => REVIEWED_BY_TOKEN,
+            FooterToken::AckedBy => ACKED_BY_TOKEN,
+            FooterToken::Refs => REFS_TOKEN,
+            FooterToken::Closes => CLOSES_TOKEN,
+            FooterToken::Fixes => FIXES_TOKEN,
+            FooterToken::Other(token) => token,
+        }
+    }
+}
+
+impl fmt::Display for FooterToken<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for FooterToken<'a> {
+    /// Returns the well-known token that `s` is, or another one.
+    fn from(s: &'a str) -> Self {
+        if s == BREAKING_CHANGE_TOKEN || s == BREAKING_CHANGE_TOKEN_ALIAS {
+            return FooterToken::BreakingChange;
+        }
+        [
+            FooterToken::SignedOffBy,
+            FooterToken::CoAuthoredBy,
+            FooterToken::ReviewedBy,
+            FooterToken::AckedBy,
+            FooterToken::Refs,
+            FooterToken::Closes,
+            FooterToken::Fixes,
+        ]
+        .iter()
+        .copied()
+        .find(|token| token.as_str().eq_ignore_ascii_case(s))
+        .unwrap_or(FooterToken::Other(s))
+    }
+}
@@ -0,0 +1,176 @@
+//! Footer parsing that follows the rules of `git interpret-trailers`.
+
+use crate::{
+    link,
+    parser::{is_blank, strip_cr},
+    Footer, FooterSeparator, FooterSpans, ParseOptions, Span,
+};
+
+/// How the footers of a commit message are recognized.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum FooterMode {
+    /// As described by the specification.
+    Conventional,
+    /// As `git interpret-trailers --parse` does.
+    ///
+    /// Only the last paragraph of the message is looked at. Trailers use the
+    /// [`ParseOptions::trailer_separators`] and may have whitespace in front
+    /// of them, as in `Token : value`. Lines of the paragraph that are not
+    /// trailers are dropped, and `BREAKING CHANGE` is not recognized as a
+    /// token since it contains a space. Parsing trailers never fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::{Commit, FooterMode, ParseOptions};
+    ///
+    /// let options = ParseOptions {
+    ///     footer_mode: FooterMode::GitTrailers,
+    ///     trailer_separators: ":→".to_string(),
+    ///     ..ParseOptions::default()
+    /// };
+    /// let commit = Commit::parse_with("fix: x\n\nAcked-by : Jane\nSee→ #12", &options).unwrap();
+    /// let values: Vec<_> = commit.footer.iter().map(|footer| footer.value).collect();
+    /// assert_eq!(values, ["Jane", "#12"]);
+    /// ```
+    GitTrailers,
+}
+
+impl Default for FooterMode {
+    /// Returns the default FooterMode, the Conventional.
+    fn default() -> Self {
+        FooterMode::Conventional
+    }
+}
+
+/// The prefixes of the lines that git itself adds to messages.
+const GIT_GENERATED_PREFIXES: &[&str] = &["Signed-off-by: ", "(cherry picked from commit "];
+
+/// Returns the byte offset at which the trailer block of `content` starts.
+///
+/// As in git, only the last paragraph can be a trailer block. It has to
+/// consist of trailers only, or at least a quarter of its lines have to be
+/// trailers if one of them was generated by git. Lines that start with
+/// whitespace continue the previous trailer. If there is no trailer block,
+/// the length of `content` is returned.
+pub(crate) fn find_trailer_block(content: &str, options: &ParseOptions) -> usize {
+    let mut start = 0;
+    let mut offset = 0;
+    for line in content.split('\n') {
+        offset += line.len() + 1;
+        if is_blank(line) {
+            start = offset;
+        }
+    }
+    if start >= content.len() {
+        return content.len();
+    }
+
+    let mut trailers = 0;
+    let mut others = 0;
+    let mut git_generated = false;
+    for (idx, line) in content[start..].split('\n').map(strip_cr).enumerate() {
+        if idx > 0 && line.starts_with(char::is_whitespace) {
+            continue;
+        }
+        if GIT_GENERATED_PREFIXES
+            .iter()
+            .any(|prefix| line.starts_with(prefix))
+        {
+            git_generated = true;
+            trailers += 1;
+        } else if split_trailer(line, options).is_some() {
+            trailers += 1;
+        } else {
+            others += 1;
+        }
+    }
+
+    if (trailers > 0 && others == 0) || (git_generated && trailers * 3 >= others) {
+        start
+    } else {
+        content.len()
+    }
+}
+
+/// Parses the trailers of a trailer block and passes them to `f`, in order.
+///
+/// Lines of the block that are not trailers are skipped, as git does.
+pub(crate) fn parse_trailers<'a>(
+    input: &'a str,
+    block: &'a str,
+    options: &ParseOptions,
+    mut f: impl FnMut(Footer<'a>, FooterSpans),
+) {
+    let mut current: Option<(usize, usize)> = None;
+    let mut offset = 0;
+    for raw in block.split('\n') {
+        let line = strip_cr(raw);
+        let end = offset + line.len();
+        if line.starts_with(char::is_whitespace) && !is_blank(line) {
+            if let Some((_, current_end)) = current.as_mut() {
+                *current_end = end;
+            }
+        } else {
+            if let Some((start, end)) = current.take() {
+                if let Some((trailer, spans)) = parse_trailer(input, &block[start..end], options) {
+                    f(trailer, spans);
+                }
+            }
+            if split_trailer(line, options).is_some() {
+                current = Some((offset, end));
+            }
+        }
+        offset += raw.len() + 1;
+    }
+    if let Some((start, end)) = current {
+        if let Some((trailer, spans)) = parse_trailer(input, &block[start..end], options) {
+            f(trailer, spans);
+        }
+    }
+}
+
+/// Parses a single trailer, which may span multiple lines.
+fn parse_trailer<'a>(
+    input: &'a str,
+    text: &'a str,
+    options: &ParseOptions,
+) -> Option<(Footer<'a>, FooterSpans)> {
+    let (token, separator, value) = split_trailer(text, options)?;
+    let value = value.trim();
+    let spans = FooterSpans {
+        token: Span::of(input, token),
+        separator: Span::of(input, separator),
+        value: Span::of(input, value),
+    };
+    Some((
+        Footer::from(token, FooterSeparator::from(separator), value),
+        spans,
+    ))
+}
+
+/// Splits a trailer into its token, separator and value.
+///
+/// Unlike footers, git allows whitespace in front of the separator and does
+/// not require a space after it, as in `Token : value` or `Token:value`. The
+/// separator is any of the [`ParseOptions::trailer_separators`].
+fn split_trailer<'a>(line: &'a str, options: &ParseOptions) -> Option<(&'a str, &'a str, &'a str)> {
+    let token_len = line
+        .find(|c: char| !(c.is_alphanumeric() || c == '-'))
+        .unwrap_or(line.len());
+    if token_len == 0 {
+        return None;
+    }
+    let (token, rest) = line.split_at(token_len);
+    let separator_start = rest.find(|c: char| c != ' ' && c != '\t')?;
+    let separator_char = rest[separator_start..].chars().next()?;
+    if !options.trailer_separators.contains(separator_char) {
+        return None;
+    }
+    let (separator, value) = rest.split_at(separator_start + separator_char.len_utf8());
+    if link::is_link_separator(separator, value) {
+        return None;
+    }
+    let separator_end = rest.len() - value.trim_start_matches(' ').len();
+    Some((token, &rest[..separator_end], &rest[separator_end..]))
+}
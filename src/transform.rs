@@ -0,0 +1,57 @@
+use crate::{Commit, CommitCow, FooterCow};
+use std::borrow::Cow;
+
+/// A component of a commit that [`Commit::map`] passes to its closure.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Component {
+    /// The type.
+    Type,
+    /// The scope.
+    Scope,
+    /// The description.
+    Desc,
+    /// The body.
+    Body,
+    /// The token of a footer.
+    FooterToken,
+    /// The value of a footer.
+    FooterValue,
+}
+
+/// Rebuilds a commit from its components, as rewritten by `f`.
+pub(crate) fn map<'a, F>(commit: &Commit<'a>, mut f: F) -> CommitCow<'a>
+where
+    F: FnMut(Component, &'a str) -> Cow<'a, str>,
+{
+    let footer: Vec<_> = commit
+        .footer
+        .iter()
+        .map(|footer| FooterCow {
+            token: f(Component::FooterToken, footer.token),
+            separator: Cow::Borrowed(footer.separator.as_str()),
+            value: f(Component::FooterValue, footer.value),
+        })
+        .collect();
+    let is_breaking_change = commit.is_breaking_change
+        || footer
+            .iter()
+            .any(|footer| footer.as_footer().is_breaking_change());
+    CommitCow {
+        autosquash: commit.autosquash,
+        body: commit
+            .body
+            .map(|body| f(Component::Body, body))
+            .filter(|body| !body.trim().is_empty()),
+        desc: f(Component::Desc, commit.desc),
+        extensions: commit.extensions.clone(),
+        footer,
+        gitmoji: commit.gitmoji.map(Cow::Borrowed),
+        has_breaking_marker: commit.has_breaking_marker,
+        is_breaking_change,
+        scope: commit
+            .scope
+            .map(|scope| f(Component::Scope, scope))
+            .filter(|scope| !scope.trim().is_empty()),
+        ty: f(Component::Type, commit.ty),
+    }
+}
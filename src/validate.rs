@@ -0,0 +1,204 @@
+use crate::{error, parser, template, ParseError, ParseErrorKind, ParseOptions, Span};
+use std::{error::Error, fmt, ops::Range};
+
+/// The rules that a commit message is validated against.
+///
+/// Besides the specification, which is checked as configured by
+/// [`Rules::options`], the rules cover the conventions that teams commonly
+/// agree on, like the allowed types or the maximum length of the header.
+/// Only the rules of the specification are enabled by default.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Rules {
+    /// The options used to parse the message.
+    pub options: ParseOptions,
+    /// The allowed types. All types are allowed if this is `None`.
+    pub types: Option<Vec<String>>,
+    /// The allowed scopes. All scopes are allowed if this is `None`.
+    pub scopes: Option<Vec<String>>,
+    /// Whether every commit has to have a scope.
+    pub require_scope: bool,
+    /// The maximum number of characters of the header.
+    pub max_header_length: Option<usize>,
+    /// The maximum number of characters of each line of the body.
+    pub max_body_line_length: Option<usize>,
+    /// Whether the description may end with a period.
+    pub forbid_trailing_period: bool,
+}
+
+impl Rules {
+    /// Returns a message template for `git config commit.template` that
+    /// explains the rules.
+    ///
+    /// The template starts with an empty line for the header, followed by
+    /// comments that git strips from the message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::Rules;
+    ///
+    /// let rules = Rules {
+    ///     types: Some(vec!["feat".to_string(), "fix".to_string()]),
+    ///     max_header_length: Some(72),
+    ///     ..Rules::default()
+    /// };
+    /// assert!(rules.template().starts_with("\n# type(scope): description\n#\n# Allowed types: feat, fix\n"));
+    /// ```
+    pub fn template(&self) -> String {
+        template::template(self)
+    }
+}
+
+/// The different kinds of violations of the [`Rules`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ViolationKind {
+    /// The message does not follow the specification.
+    Syntax(ParseErrorKind),
+    /// The type is not one of the [`Rules::types`].
+    TypeNotAllowed,
+    /// A scope is not one of the [`Rules::scopes`].
+    ScopeNotAllowed,
+    /// The header has no scope although [`Rules::require_scope`] is set.
+    MissingScope,
+    /// The header is longer than [`Rules::max_header_length`].
+    HeaderTooLong,
+    /// A line of the body is longer than [`Rules::max_body_line_length`].
+    BodyLineTooLong,
+    /// The description ends with a period although
+    /// [`Rules::forbid_trailing_period`] is set.
+    TrailingPeriod,
+}
+
+impl fmt::Display for ViolationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            ViolationKind::Syntax(kind) => return write!(f, "{}", kind),
+            ViolationKind::TypeNotAllowed => "the type is not allowed",
+            ViolationKind::ScopeNotAllowed => "the scope is not allowed",
+            ViolationKind::MissingScope => "missing scope",
+            ViolationKind::HeaderTooLong => "the header is too long",
+            ViolationKind::BodyLineTooLong => "the line is too long",
+            ViolationKind::TrailingPeriod => "the description must not end with a period",
+        };
+        write!(f, "{}", msg)
+    }
+}
+
+/// A violation of the [`Rules`], found by
+/// [`Commit::validate`](crate::Commit::validate).
+///
+/// Like a [`ParseError`], a violation records where in the message it was
+/// found, both as a byte range and as a line and column.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Violation {
+    kind: ViolationKind,
+    span: Span,
+    line: usize,
+    column: usize,
+}
+
+impl Violation {
+    /// Creates a violation of the given kind that covers `span` of `input`.
+    pub fn at(kind: ViolationKind, input: &str, span: Span) -> Self {
+        let (line, column) = error::location(input, span.start);
+        Self {
+            kind,
+            span,
+            line,
+            column,
+        }
+    }
+
+    /// Returns the kind of the violation.
+    pub fn kind(&self) -> ViolationKind {
+        self.kind
+    }
+
+    /// Returns the byte range of the message that violates the rules.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Returns the line at which the violation occurred, starting at 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column at which the violation occurred, starting at 1.
+    ///
+    /// Columns are counted in characters, not bytes.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl From<ParseError> for Violation {
+    fn from(error: ParseError) -> Self {
+        Self {
+            kind: ViolationKind::Syntax(error.kind()),
+            span: error.span(),
+            line: error.line(),
+            column: error.column(),
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )
+    }
+}
+
+impl Error for Violation {}
+
+/// Validates a commit message against the rules.
+pub(crate) fn validate(input: &str, rules: &Rules) -> Vec<Violation> {
+    let mut errors = Vec::new();
+    let (commit, spans) = parser::parse(input, &rules.options, &mut errors);
+    let mut violations: Vec<_> = errors.into_iter().map(Violation::from).collect();
+    let mut violation = |kind, span| violations.push(Violation::at(kind, input, span));
+
+    if let Some(max) = rules.max_header_length {
+        if input[Range::from(spans.header)].chars().count() > max {
+            violation(ViolationKind::HeaderTooLong, spans.header);
+        }
+    }
+    if let Some(types) = &rules.types {
+        if !commit.ty.is_empty() && !types.iter().any(|ty| ty == commit.ty) {
+            violation(ViolationKind::TypeNotAllowed, spans.ty);
+        }
+    }
+    if let Some(scopes) = &rules.scopes {
+        for scope in commit.scopes() {
+            if !scopes.iter().any(|allowed| allowed == scope) {
+                violation(ViolationKind::ScopeNotAllowed, Span::of(input, scope));
+            }
+        }
+    }
+    if rules.require_scope && commit.scope.is_none() {
+        violation(
+            ViolationKind::MissingScope,
+            Span::new(spans.ty.end, spans.ty.end),
+        );
+    }
+    if rules.forbid_trailing_period && commit.desc.ends_with('.') {
+        violation(
+            ViolationKind::TrailingPeriod,
+            Span::new(spans.desc.end - 1, spans.desc.end),
+        );
+    }
+    if let (Some(max), Some(body)) = (rules.max_body_line_length, commit.body) {
+        for line in body.split('\n').map(parser::strip_cr) {
+            if line.chars().count() > max {
+                violation(ViolationKind::BodyLineTooLong, Span::of(input, line));
+            }
+        }
+    }
+
+    violations.sort_by_key(|violation| violation.span.start);
+    violations
+}
@@ -0,0 +1,162 @@
+//! A versioned envelope for serialized commits.
+
+use crate::{Autosquash, Commit, Extensions, Footer, FooterSeparator};
+use serde::{de::Error, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+
+/// The version of the serde representation of commits written by this
+/// release.
+///
+/// It is increased whenever the representation changes in a way that older
+/// releases would misread.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// A commit serialized together with the version of its representation, as
+/// `{"version":1,"commit":{...}}`.
+///
+/// Deserializing fails for versions newer than [`SCHEMA_VERSION`], so that
+/// commits cached by a newer release are not silently misread. Unknown fields
+/// are ignored, unless [`Versioned::deserialize_strict`] is used.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{Commit, Versioned};
+///
+/// let versioned = Versioned::from(Commit::parse("feat: cache commits").unwrap());
+/// let json = serde_json::to_string(&versioned).unwrap();
+/// assert!(json.starts_with(r#"{"version":1,"commit":{"#));
+///
+/// let newer = json.replacen("1", "2", 1);
+/// assert!(serde_json::from_str::<Versioned<'_>>(&newer).is_err());
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Versioned<'a> {
+    /// The commit.
+    pub commit: Commit<'a>,
+}
+
+impl<'a> From<Commit<'a>> for Versioned<'a> {
+    fn from(commit: Commit<'a>) -> Self {
+        Self { commit }
+    }
+}
+
+impl<'a> Versioned<'a> {
+    /// Deserializes a versioned commit, failing for fields that are not part
+    /// of the representation.
+    ///
+    /// Use it for data that must have been written by the same release, or
+    /// with `#[serde(deserialize_with = "Versioned::deserialize_strict")]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conventional_commits_types::Versioned;
+    ///
+    /// let json = r#"{"version":1,"commit":{"autosquash":null,"body":null,"desc":"x","footer":[],
+    ///     "gitmoji":null,"is_breaking_change":false,"scope":null,"ty":"feat","extra":1}}"#;
+    /// assert!(serde_json::from_str::<Versioned<'_>>(json).is_ok());
+    ///
+    /// let mut deserializer = serde_json::Deserializer::from_str(json);
+    /// assert!(Versioned::deserialize_strict(&mut deserializer).is_err());
+    /// ```
+    pub fn deserialize_strict<D: Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+        let envelope = StrictEnvelope::deserialize(deserializer)?;
+        check_version(envelope.version)?;
+        let commit = envelope.commit;
+        Ok(Self::from(Commit {
+            autosquash: commit.autosquash,
+            body: commit.body,
+            desc: commit.desc,
+            extensions: commit.extensions,
+            footer: commit
+                .footer
+                .into_iter()
+                .map(|footer| Footer::from(footer.token, footer.separator, footer.value))
+                .collect(),
+            gitmoji: commit.gitmoji,
+            has_breaking_marker: commit.has_breaking_marker,
+            is_breaking_change: commit.is_breaking_change,
+            scope: commit.scope,
+            ty: commit.ty,
+        }))
+    }
+}
+
+impl Serialize for Versioned<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut envelope = serializer.serialize_struct("Versioned", 2)?;
+        envelope.serialize_field("version", &SCHEMA_VERSION)?;
+        envelope.serialize_field("commit", &self.commit)?;
+        envelope.end()
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for Versioned<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let envelope = Envelope::deserialize(deserializer)?;
+        check_version(envelope.version)?;
+        Ok(Self::from(envelope.commit))
+    }
+}
+
+/// Fails for versions this release cannot read.
+fn check_version<E: Error>(version: u32) -> Result<(), E> {
+    if version == 0 || version > SCHEMA_VERSION {
+        Err(E::custom(format_args!(
+            "unsupported schema version {}, expected at most {}",
+            version, SCHEMA_VERSION
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// The serialized form of [`Versioned`].
+#[derive(Deserialize)]
+struct Envelope<'a> {
+    version: u32,
+    #[serde(borrow)]
+    commit: Commit<'a>,
+}
+
+/// The serialized form of [`Versioned`], without unknown fields.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StrictEnvelope<'a> {
+    version: u32,
+    #[serde(borrow)]
+    commit: StrictCommit<'a>,
+}
+
+/// A [`Commit`] without unknown fields.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StrictCommit<'a> {
+    autosquash: Option<Autosquash>,
+    #[serde(borrow)]
+    body: Option<&'a str>,
+    desc: &'a str,
+    #[serde(default)]
+    extensions: Extensions,
+    #[serde(borrow)]
+    footer: Vec<StrictFooter<'a>>,
+    #[serde(borrow)]
+    gitmoji: Option<&'a str>,
+    #[serde(default)]
+    has_breaking_marker: bool,
+    is_breaking_change: bool,
+    #[serde(borrow)]
+    scope: Option<&'a str>,
+    ty: &'a str,
+}
+
+/// A [`Footer`] without unknown fields.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StrictFooter<'a> {
+    token: &'a str,
+    #[serde(borrow)]
+    separator: FooterSeparator<'a>,
+    value: &'a str,
+}
@@ -0,0 +1,102 @@
+use crate::{Commit, CommitCow, CommitOwned};
+use std::{
+    borrow::Borrow,
+    hash::{Hash, Hasher},
+};
+
+/// A commit in any of its representations.
+///
+/// [`Commit`] is the common view of all of them: it borrows the components
+/// from wherever they are stored, be it the message or an owned commit.
+/// Functions that take an `impl AsCommit` therefore accept [`Commit`],
+/// [`CommitCow`] and [`CommitOwned`] alike, and references to them, while
+/// being written against [`Commit`] only. Through `dyn AsCommit`, which all of
+/// them [`Borrow`], a set or map keyed by one representation can be searched
+/// with another, since all of them hash and compare the same way.
+///
+/// # Example
+///
+/// ```
+/// use conventional_commits_types::{AsCommit, Commit, CommitOwned};
+/// use std::collections::HashSet;
+///
+/// let mut seen = HashSet::new();
+/// seen.insert(CommitOwned::parse("feat: add a cache").unwrap());
+///
+/// let commit = Commit::parse("feat: add a cache").unwrap();
+/// assert!(seen.contains(&commit as &dyn AsCommit));
+///
+/// fn summaries(commits: &[impl AsCommit]) -> Vec<String> {
+///     commits.iter().map(|commit| commit.as_commit().summary()).collect()
+/// }
+/// assert_eq!(summaries(&[commit]), ["feat: add a cache"]);
+/// assert_eq!(summaries(&seen.into_iter().collect::<Vec<_>>()), ["feat: add a cache"]);
+/// ```
+pub trait AsCommit {
+    /// Returns a commit that borrows the components of this one.
+    fn as_commit(&self) -> Commit<'_>;
+}
+
+impl AsCommit for Commit<'_> {
+    fn as_commit(&self) -> Commit<'_> {
+        self.clone()
+    }
+}
+
+impl AsCommit for CommitCow<'_> {
+    fn as_commit(&self) -> Commit<'_> {
+        CommitCow::as_commit(self)
+    }
+}
+
+impl AsCommit for CommitOwned {
+    fn as_commit(&self) -> Commit<'_> {
+        CommitOwned::as_commit(self)
+    }
+}
+
+impl<T: AsCommit + ?Sized> AsCommit for &T {
+    fn as_commit(&self) -> Commit<'_> {
+        (**self).as_commit()
+    }
+}
+
+impl PartialEq for dyn AsCommit + '_ {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_commit() == other.as_commit()
+    }
+}
+
+impl Eq for dyn AsCommit + '_ {}
+
+impl Hash for dyn AsCommit + '_ {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_commit().hash(state);
+    }
+}
+
+impl ToOwned for dyn AsCommit + '_ {
+    type Owned = CommitOwned;
+
+    fn to_owned(&self) -> CommitOwned {
+        CommitOwned::from(self.as_commit())
+    }
+}
+
+impl<'a> Borrow<dyn AsCommit + 'a> for Commit<'a> {
+    fn borrow(&self) -> &(dyn AsCommit + 'a) {
+        self
+    }
+}
+
+impl<'a> Borrow<dyn AsCommit + 'a> for CommitCow<'a> {
+    fn borrow(&self) -> &(dyn AsCommit + 'a) {
+        self
+    }
+}
+
+impl<'a> Borrow<dyn AsCommit + 'a> for CommitOwned {
+    fn borrow(&self) -> &(dyn AsCommit + 'a) {
+        self
+    }
+}